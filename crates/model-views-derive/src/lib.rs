@@ -13,7 +13,8 @@
 //! - **`{Model}Patch`**: A view for partial updates using the `Patch<T>` wrapper
 //!
 //! Each generated type only includes fields relevant to its access mode, based on
-//! field-level attributes that specify visibility policies.
+//! field-level attributes that specify visibility policies. The singular `#[view(...)]`
+//! is accepted as an alias of `#[views(...)]` everywhere.
 //!
 //! # Field Policies
 //!
@@ -22,6 +23,7 @@
 //! - `#[views(get = "policy")]`: Controls field visibility in the Get view
 //!   - `"required"` (default): Field is always present
 //!   - `"optional"`: Field is wrapped in `Option<T>`
+//!   - `"flatten_option"`: An `Option<T>` field stays a single `Option<T>`
 //!   - `"forbidden"`: Field is excluded from this view
 //!
 //! - `#[views(create = "policy")]`: Controls field visibility in the Create view
@@ -30,14 +32,65 @@
 //!   - `"forbidden"`: Field is excluded from this view
 //!
 //! - `#[views(patch = "policy")]`: Controls field visibility in the Patch view
-//!   - `"patch"` (default): Field is wrapped in `Patch<T>`
+//!   - `"required"` or `"patch"` (default): Field is wrapped in `Patch<T>`
 //!   - `"optional"`: Field is wrapped in `Patch<Option<T>>`
+//!   - `"replace"`: Field is a plain `Option<T>` (`None` means "no change")
+//!   - `"tri"`: Field is a `PatchOpt<T>` (ignore, set to null, or set a value)
 //!   - `"forbidden"`: Field is excluded from this view
 //!
+//! - `#[views(field = "name")]`: Rename the field in all generated views
+//! - `#[views(alias = "name")]`: Accept another name for the field when deserializing
+//! - `#[views(opaque)]`: Use the field type verbatim instead of its `View` projection;
+//!   `get_type`, `create_type` and `patch_type` override the type for a single view
+//! - `#[views(via = "Type")]`: Represent the field as `Type` in all views, converting with
+//!   `From`/`Into`
+//! - `#[views(redact)]`: Print `***` instead of the field's value in the views' `Debug` output
+//!
 //! # Container Attributes
 //!
 //! - `#[views(crate = "path")]`: Override the path to the `model_views` crate
+//! - `#[views(base_name = "Name")]`: Name the views `NameGet` etc. instead of after the struct
+//! - `#[views(public)]`: Make all generated types `pub` regardless of the struct's visibility
 //! - `#[views(serde)]`: Automatically derive `Serialize`/`Deserialize` for generated types
+//! - `#[views(rename_all = "...")]`: Serde casing for all generated types, overridable per
+//!   view with `get_rename_all`, `create_rename_all` and `patch_rename_all`
+//! - `#[views(serde_bound = "...")]`: Serde `bound` for all generated types, overridable per
+//!   view with `get_serde_bound`, `create_serde_bound` and `patch_serde_bound`
+//! - `#[views(create_allow_unknown_fields)]`: Accept unknown keys in the Create view only
+//!   (likewise `get_allow_unknown_fields` and `patch_allow_unknown_fields`)
+//! - `#[views(get_derive(Trait, ...))]`: Derive additional traits on the Get view (likewise
+//!   `create_derive` and `patch_derive`)
+//! - `#[views(create_validate = "path", create_validate_error = "Type")]`: Generate a
+//!   `validate` method on the Create view (likewise `patch_validate` for the Patch view)
+//! - `#[views(create_xor = ["a", "b"])]`: Require exactly one of the optional Create fields
+//!   when deserializing
+//! - `#[views(patch_normalize = "path")]`: Generate a `normalized` method on the Patch view
+//! - `#[views(patch_null_is_error)]`: Reject explicit `null` for `Patch<T>` fields
+//! - `#[views(patch_assert)]`: Generate an `applies_to` test helper on the Patch view
+//! - `#[views(create_new)]`: Generate a `new` constructor on the Create view
+//! - `#[views(create_introspect)]`: Generate `missing_optionals` on the Create view
+//! - `#[views(create_default)]`: Derive `Default` for the Create view
+//! - `#[views(get_introspect)]`: Generate `present_optionals` on the Get view
+//! - `#[views(get_try_into)]`: Generate `TryFrom<{Model}Get> for {Model}`
+//! - `#[views(get_accessors)]`: Make the Get view's fields private behind getters (likewise
+//!   `create_accessors` and `patch_accessors`)
+//! - `#[views(get_ref)]`: Generate a borrowed `{Model}GetRef<'a>` variant of the Get view
+//! - `#[views(transparent)]`: Generate newtype views for a single-field struct
+//! - `#[views(merge)]`: Implement `PatchMerge` for the generated Patch view
+//! - `#[views(forward_repr)]`: Copy the model's `#[repr(...)]` to the generated types
+//! - `#[views(patch_apply)]`: Implement `ApplyPatch` for the model
+//! - `#[views(into_view)]`: Implement `IntoView` for the model in every mode
+//! - `#[views(runtime_dispatch)]`: Generate a `{Model}AnyView` enum deserialized by a
+//!   runtime `ViewModeKind`
+//! - `#[views(get_from)]`: Implement `From<Model>` for the Get view (likewise `create_from`)
+//! - `#[views(get_eq_model)]`: Generate `matches_model` on the Get view
+//! - `#[views(skip_serializing_none)]`: Skip `None` values of all `Option` fields when serializing
+//! - `#[views(patch_non_empty)]`: Generate `require_change` on the Patch view
+//! - `#[views(merge_patch_serialize)]`: Serialize the Patch view as an RFC 7396 JSON Merge
+//!   Patch document
+//! - `#[views(patch_columns)]`: Generate a `{Model}PatchColumn` enum and `set_columns` on the
+//!   Patch view
+//! - `#[views(patch_to_create)]`: Generate `TryFrom<{Model}Patch> for {Model}Create`
 //!
 //! # Example
 //!
@@ -57,45 +110,241 @@
 //! ```
 //!
 //! This generates:
-//! - `UserGet` with `id: i64`, `name: String`, `email: Option<Option<String>>`
-//! - `UserCreate` with `name: String`, `email: Option<Option<String>>`
+//! - `UserGet` with `id: i64`, `name: String`, `email: Option<String>`
+//! - `UserCreate` with `name: String`, `email: Option<String>`
 //! - `UserPatch` with `name: Patch<String>`, `email: Patch<Option<String>>`
 
-#![allow(clippy::option_if_let_else)]
+#![allow(clippy::option_if_let_else, clippy::needless_continue)]
 
-use darling::{FromDeriveInput, FromField, util::Ignored};
+use darling::{
+    FromDeriveInput, FromField,
+    util::{Ignored, PathList},
+};
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{DeriveInput, Type, parse_macro_input};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{DeriveInput, Type, ext::IdentExt, parse_macro_input, spanned::Spanned};
 
 const BASE_CRATE: &str = "model_views";
 
 #[derive(FromDeriveInput)]
-#[darling(attributes(views))]
+#[darling(attributes(views, view))]
 struct ViewsInput {
     ident: syn::Ident,
     vis: syn::Visibility,
     generics: syn::Generics,
     data: darling::ast::Data<Ignored, ViewsField>,
     /// Path (string) to base crate, e.g. "`model_views`"
-    #[darling(default)]
+    #[darling(default, rename = "crate")]
     crate_: Option<String>,
+    /// Prefix of the generated view type names, overriding the struct name
+    #[darling(default)]
+    base_name: Option<String>,
+    /// Whether to make the generated types `pub` instead of inheriting the visibility
+    #[darling(default)]
+    public: Option<bool>,
     /// Whether to derive serde traits for the generated types
     #[darling(default)]
     serde: Option<bool>,
+    /// Serde `rename_all` rule for all generated types
+    #[darling(default)]
+    rename_all: Option<String>,
+    /// Serde `rename_all` rule for the Get view, overriding `rename_all`
+    #[darling(default)]
+    get_rename_all: Option<String>,
+    /// Serde `rename_all` rule for the Create view, overriding `rename_all`
+    #[darling(default)]
+    create_rename_all: Option<String>,
+    /// Serde `rename_all` rule for the Patch view, overriding `rename_all`
+    #[darling(default)]
+    patch_rename_all: Option<String>,
+    /// Serde `bound` for all generated types
+    #[darling(default)]
+    serde_bound: Option<String>,
+    /// Serde `bound` for the Get view, overriding `serde_bound`
+    #[darling(default)]
+    get_serde_bound: Option<String>,
+    /// Serde `bound` for the Create view, overriding `serde_bound`
+    #[darling(default)]
+    create_serde_bound: Option<String>,
+    /// Serde `bound` for the Patch view, overriding `serde_bound`
+    #[darling(default)]
+    patch_serde_bound: Option<String>,
+    /// Whether the Get view omits `deny_unknown_fields`
+    #[darling(default)]
+    get_allow_unknown_fields: Option<bool>,
+    /// Whether the Create view omits `deny_unknown_fields`
+    #[darling(default)]
+    create_allow_unknown_fields: Option<bool>,
+    /// Whether the Patch view omits `deny_unknown_fields`
+    #[darling(default)]
+    patch_allow_unknown_fields: Option<bool>,
+    /// Additional derives for the Get view
+    #[darling(default)]
+    get_derive: PathList,
+    /// Additional derives for the Create view
+    #[darling(default)]
+    create_derive: PathList,
+    /// Additional derives for the Patch view
+    #[darling(default)]
+    patch_derive: PathList,
+    /// Whether the views of a single-field struct are transparent newtypes
+    #[darling(default)]
+    transparent: Option<bool>,
+    /// Whether to implement `PatchMerge` for the generated Patch view
+    #[darling(default)]
+    merge: Option<bool>,
+    /// Whether to copy `#[repr(...)]` of the model to the generated types
+    #[darling(default)]
+    forward_repr: Option<bool>,
+    /// Whether to implement `From<Model>` for the Get view
+    #[darling(default)]
+    get_from: Option<bool>,
+    /// Whether to implement `From<Model>` for the Create view
+    #[darling(default)]
+    create_from: Option<bool>,
+    /// Whether to generate `matches_model` on the Get view
+    #[darling(default)]
+    get_eq_model: Option<bool>,
+    /// Whether to generate an enum of all views, deserialized by a runtime mode
+    #[darling(default)]
+    runtime_dispatch: Option<bool>,
+    /// Whether to implement `IntoView` for the model in every mode
+    #[darling(default)]
+    into_view: Option<bool>,
+    /// Whether to skip `None` values of `Option` fields in all views when serializing
+    #[darling(default)]
+    skip_serializing_none: Option<bool>,
+    /// Whether to implement `ApplyPatch` for the model
+    #[darling(default)]
+    patch_apply: Option<bool>,
+    /// Whether to generate `require_change` on the Patch view
+    #[darling(default)]
+    patch_non_empty: Option<bool>,
+    /// Whether to implement `Serialize` for the Patch view as a JSON Merge Patch document
+    #[darling(default)]
+    merge_patch_serialize: Option<bool>,
+    /// Whether to generate an enum of the patchable columns and `set_columns`
+    #[darling(default)]
+    patch_columns: Option<bool>,
+    /// Whether to generate `TryFrom<{Model}Patch> for {Model}Create`
+    #[darling(default)]
+    patch_to_create: Option<bool>,
+    /// Whether to generate `TryFrom<{Model}Get> for {Model}`
+    #[darling(default)]
+    get_try_into: Option<bool>,
+    /// Whether to generate a `new` constructor on the Create view
+    #[darling(default)]
+    create_new: Option<bool>,
+    /// Whether to generate `missing_optionals` on the Create view
+    #[darling(default)]
+    create_introspect: Option<bool>,
+    /// Whether to generate `present_optionals` on the Get view
+    #[darling(default)]
+    get_introspect: Option<bool>,
+    /// Whether to derive `Default` for the Create view
+    #[darling(default)]
+    create_default: Option<bool>,
+    /// Whether the Get view has private fields with getters
+    #[darling(default)]
+    get_accessors: Option<bool>,
+    /// Whether the Create view has private fields with getters
+    #[darling(default)]
+    create_accessors: Option<bool>,
+    /// Whether the Patch view has private fields with getters
+    #[darling(default)]
+    patch_accessors: Option<bool>,
+    /// Whether to generate a borrowed `{Model}GetRef<'a>` variant of the Get view
+    #[darling(default)]
+    get_ref: Option<bool>,
+    /// Path to a validation function for the Create view
+    #[darling(default)]
+    create_validate: Option<String>,
+    /// Error type returned by the Create validation function
+    #[darling(default)]
+    create_validate_error: Option<String>,
+    /// Groups of mutually exclusive optional Create fields, exactly one of which must be set
+    #[darling(multiple)]
+    create_xor: Vec<Vec<syn::LitStr>>,
+    /// Path to a validation function for the Patch view
+    #[darling(default)]
+    patch_validate: Option<String>,
+    /// Error type returned by the Patch validation function
+    #[darling(default)]
+    patch_validate_error: Option<String>,
+    /// Whether an explicit `null` for a `patch = "required"` field is a deserialize error
+    #[darling(default)]
+    patch_null_is_error: Option<bool>,
+    /// Whether to generate `applies_to` on the Patch view
+    #[darling(default)]
+    patch_assert: Option<bool>,
+    /// Path to a normalization function for the Patch view
+    #[darling(default)]
+    patch_normalize: Option<String>,
 }
 
 #[derive(FromField, Clone)]
-#[darling(attributes(views))]
+#[darling(attributes(views, view), forward_attrs(doc, serde))]
 struct ViewsField {
     ident: Option<syn::Ident>,
     ty: Type,
+    attrs: Vec<syn::Attribute>,
+    /// Name of the field in the generated views, if different from the model's
+    #[darling(default)]
+    field: Option<String>,
+    /// Additional names accepted for the field when deserializing
+    #[darling(multiple)]
+    alias: Vec<String>,
     #[darling(default)]
     get: Option<String>,
     #[darling(default)]
     create: Option<String>,
+    /// Whether a required create field falls back to `Default` when omitted
+    #[darling(default)]
+    create_serde_default: Option<bool>,
+    /// Function deserializing the field in the Create view
+    #[darling(default)]
+    create_deserialize_with: Option<String>,
+    /// Function producing the value of an absent optional field in the Create view
+    #[darling(default)]
+    create_sentinel: Option<String>,
+    /// Whether a forbidden create field is still accepted, and discarded, when deserializing
+    #[darling(default)]
+    create_accept_forbidden: Option<bool>,
     #[darling(default)]
     patch: Option<String>,
+    /// Function deciding whether to skip the field when serializing the Patch view
+    #[darling(default)]
+    patch_skip_if: Option<String>,
+    /// Whether the field type is used verbatim instead of its `View` projection
+    #[darling(default)]
+    opaque: Option<bool>,
+    /// Whether the field's value is hidden in the `Debug` output of the views
+    #[darling(default)]
+    redact: Option<bool>,
+    /// Type of the field in the Get view, overriding its `View` projection
+    #[darling(default)]
+    get_type: Option<String>,
+    /// Type of the field in the Create view, overriding its `View` projection
+    #[darling(default)]
+    create_type: Option<String>,
+    /// Type of the field in the Patch view, overriding its `View` projection
+    #[darling(default)]
+    patch_type: Option<String>,
+    /// Type of the field in all views, unless overridden for a single view
+    #[darling(default)]
+    via: Option<String>,
+}
+
+impl ViewsField {
+    /// Applies `via` as the type override of every view that does not have its own.
+    fn resolve_via(mut self) -> Self {
+        if let Some(via) = &self.via {
+            self.get_type.get_or_insert_with(|| via.clone());
+            self.create_type.get_or_insert_with(|| via.clone());
+            self.patch_type.get_or_insert_with(|| via.clone());
+        }
+        self
+    }
 }
 
 /// Derives view types for different access modes from a model struct.
@@ -112,14 +361,18 @@ struct ViewsField {
 /// For a struct named `User`, the macro generates:
 /// - `UserGet` with appropriate `Serialize` derives (if serde enabled)
 /// - `UserCreate` with appropriate `Deserialize` derives (if serde enabled)
-/// - `UserPatch` with `Default` and `Deserialize` derives (if serde enabled)
+/// - `UserPatch` with `Default`, `Serialize` and `Deserialize` derives (if serde enabled)
+///
+/// Patch views also implement [`NoopPatch`](../model_views/trait.NoopPatch.html); when
+/// serializing, fields whose patch is a no-op (including nested patches that ignore all
+/// of their fields) are skipped.
 ///
 /// Each generated type implements `View<ViewMode{Get,Create,Patch}>` for the original type,
 /// allowing generic code to work with different view modes.
 ///
 /// # Container Attributes
 ///
-/// The `#[views(...)]` attribute on the struct itself accepts:
+/// The `#[views(...)]` (or `#[view(...)]`) attribute on the struct itself accepts:
 ///
 /// - `crate = "path"`: Override the path to the `model_views` crate. Useful when
 ///   re-exporting or when the crate is available under a different name.
@@ -130,9 +383,33 @@ struct ViewsField {
 ///   struct User { /* ... */ }
 ///   ```
 ///
+/// - `base_name = "Name"`: Use `Name` instead of the struct name as the prefix of the
+///   generated view types, e.g. for macro-generated structs with mangled names. The
+///   value must be a valid identifier.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(base_name = "User")]
+///   struct __GeneratedUser0 { /* ... */ } // generates UserGet, UserCreate, UserPatch
+///   ```
+///
+/// - `public`: Declare all generated types `pub` instead of giving them the struct's own
+///   visibility, e.g. for a `pub(crate)` model whose views are part of a public API. There
+///   are no per-view visibility settings, so this applies to every generated type.
+///
 /// - `serde` or `serde = true`: Automatically derive `Serialize` for Get views and
-///   `Deserialize` for Create and Patch views. Also adds `deny_unknown_fields` and
-///   appropriate field-level serde attributes.
+///   `Deserialize` for Create and Patch views (Patch views also derive `Serialize`). Also
+///   adds `deny_unknown_fields` and appropriate field-level serde attributes. Field-level
+///   `#[serde(...)]` attributes of the model are forwarded to the generated fields as far
+///   as they do not depend on the field's type (`rename`, `alias`, `flatten`, `skip`,
+///   `skip_serializing`, `skip_deserializing` and `bound`); the others, such as `with` or
+///   `skip_serializing_if`, were written for the model's field type and are dropped, since
+///   the views wrap it in `Option` or `Patch`. If any field is `#[serde(flatten)]`,
+///   `deny_unknown_fields` is omitted since serde does not support the combination. A
+///   flattened nested model inlines the fields of its own view for that mode, so e.g. a
+///   Create view can compose a shared `CoordinatesCreate`. Since every Patch view field
+///   defaults to ignoring its value, a Patch view accepts any subset of its fields,
+///   including the empty object `{}`, while an unknown key is still rejected.
 ///   
 ///   ```rust,ignore
 ///   #[derive(Views)]
@@ -140,6 +417,317 @@ struct ViewsField {
 ///   struct User { /* ... */ }
 ///   ```
 ///
+/// - `rename_all = "rule"`: With serde enabled, add `#[serde(rename_all = "rule")]` to all
+///   generated types. `get_rename_all`, `create_rename_all` and `patch_rename_all` set the
+///   rule for a single view and take precedence over `rename_all`, so e.g. a read API can
+///   use `camelCase` while a legacy create endpoint keeps `snake_case`.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, get_rename_all = "camelCase", create_rename_all = "snake_case")]
+///   struct User { /* ... */ }
+///   ```
+///
+/// - `serde_bound = "bounds"`: With serde enabled, add `#[serde(bound = "bounds")]` to all
+///   generated types, replacing the bounds serde infers for generic models.
+///   `get_serde_bound`, `create_serde_bound` and `patch_serde_bound` set the bounds for a
+///   single view and take precedence over `serde_bound`, since a Get view is only
+///   serialized and a Create view only deserialized.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, get_serde_bound = "T: Serialize", create_serde_bound = "T: Deserialize<'de>")]
+///   struct Page<T> { /* ... */ }
+///   ```
+///
+/// - `create_allow_unknown_fields`: With serde enabled, leave `deny_unknown_fields` off the
+///   Create view, so it ignores unknown keys while the other views keep rejecting them,
+///   e.g. for a create endpoint that must accept forward-compatible payloads.
+///   `get_allow_unknown_fields` and `patch_allow_unknown_fields` do the same for the Get
+///   view (deserialized with `runtime_dispatch`) and the Patch view.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, create_allow_unknown_fields)]
+///   struct User { /* ... */ }
+///   ```
+///
+/// - `get_derive(Trait, ...)`: Add `#[derive(Trait, ...)]` to the Get view, e.g. to use it
+///   as a cache key. `create_derive` and `patch_derive` do the same for the Create and Patch
+///   views. Every field type of the view must implement the derived traits; `Option`,
+///   `Patch` and `PatchOpt` forward `PartialEq`, `Eq` and `Hash` of their values. The Patch
+///   view already derives `Default`, as does the Create view with `create_default`.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(get_derive(Clone, PartialEq, Eq, Hash))]
+///   struct User { /* ... */ }
+///   ```
+///
+/// - `create_validate = "path"` and `create_validate_error = "Type"`: Generate
+///   `pub fn validate(&self) -> Result<(), Type>` on the Create view, delegating to the
+///   function at `path`. The function must have the signature
+///   `fn(&UserCreate) -> Result<(), Type>`. Both attributes must be given together.
+///   `patch_validate` and `patch_validate_error` do the same for the Patch view.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(create_validate = "validate_user", create_validate_error = "String")]
+///   struct User { /* ... */ }
+///
+///   fn validate_user(user: &UserCreate) -> Result<(), String> { /* ... */ }
+///   ```
+///
+/// - `create_xor = ["a", "b", ...]`: With serde, reject Create payloads that do not provide
+///   exactly one of the listed `create = "optional"` fields, e.g. an `email` or a `phone`
+///   but not both. The attribute may be repeated for several groups. The Create view is
+///   deserialized through a hidden unchecked twin and converted with `TryFrom`, which fails
+///   with an `ExclusiveFieldsError` whose message reads
+///   `expected exactly one of email, phone, found none` (or `found email, phone`), naming
+///   the fields by their Rust names. Constructing the view directly is not checked.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, create_xor = ["email", "phone"])]
+///   struct Contact { /* ... */ }
+///   ```
+///
+/// - `patch_normalize = "path"`: Generate `pub fn normalized(self) -> Self` on the Patch
+///   view, which passes the patch to the function at `path` for cleanup (e.g. trimming
+///   strings or dropping no-op updates) before returning it. The function must have the
+///   signature `fn(&mut UserPatch)`.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(patch_normalize = "normalize_user")]
+///   struct User { /* ... */ }
+///
+///   fn normalize_user(patch: &mut UserPatch) { /* ... */ }
+///   ```
+///
+/// - `transparent`: For a struct with exactly one field, such as the newtype
+///   `struct Email(String)`, generate views that are themselves newtypes
+///   (`EmailGet(String)`, `EmailPatch(Patch<String>)`, ...). With serde enabled, the
+///   views carry `#[serde(transparent)]` instead of `deny_unknown_fields`, so they
+///   (de)serialize exactly like their single field. Field policies still apply to that
+///   field.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, transparent)]
+///   struct Email(String);
+///   ```
+///
+/// - `forward_repr`: Copy the model's `#[repr(...)]` attributes to the generated types.
+///   By default they are dropped, since views rarely need the model's layout.
+///
+/// - `merge`: Implement `PatchMerge` for the Patch view by merging field by field. Nested
+///   patch fields recurse into the nested view's `PatchMerge` impl, so the nested model
+///   must use `merge` as well; all other values are replaced by the later update.
+///
+/// - `runtime_dispatch`: Generate `{Model}AnyView`, an enum with a `Get`, `Create` and
+///   `Patch` variant wrapping the respective view, for endpoints that pick the view at
+///   runtime. `{Model}AnyView::deserialize_view(mode, deserializer)` deserializes the view
+///   selected by a `ViewModeKind`, and `mode()` returns it back. With the `json` feature,
+///   `deserialize_view_json(mode, json)` does the same for a JSON string. Requires `serde`
+///   and all three views; the Get view then derives `Deserialize` as well, so nested models
+///   must use `runtime_dispatch` too.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, runtime_dispatch)]
+///   struct User { /* ... */ }
+///
+///   let view = UserAnyView::deserialize_view_json(ViewModeKind::Patch, body)?;
+///   ```
+///
+/// - `skip_serializing_none`: With serde enabled, add
+///   `#[serde(skip_serializing_if = "Option::is_none")]` to every `Option` field of the Get
+///   views, like `serde_with::skip_serializing_none`. This covers fields wrapped by the
+///   `"optional"` and `"flatten_option"` policies as well as fields whose model type is an
+///   `Option`. Create views are only deserialized, and Patch view fields already skip
+///   no-op patches.
+///
+/// - `into_view`: Implement `IntoView<M>` for the model in every mode that has a view,
+///   converting field by field, along with `pub fn to_view<M>(self)` on the model.
+///   Nested models are converted through their own `IntoView` impls, so they must use
+///   `into_view` as well; opaque fields are moved as they are, and fields with a type
+///   override are converted with `Into`. The Patch view updates every present field.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(into_view)]
+///   struct User { /* ... */ }
+///
+///   let get = user.to_view::<ViewModeGet>();
+///   ```
+///
+/// - `patch_apply`: Implement `ApplyPatch` for the model, updating it in place from a
+///   Patch view. Updated fields of nested models recurse into the nested model's
+///   `ApplyPatch` impl, so the nested model must use `patch_apply` as well; all other
+///   values are replaced. Fields with a `patch_type` override are converted with `Into`.
+///   Updates to null (`Patch::Update(None)` for `patch = "optional"`,
+///   `PatchOpt::SetNull`) reset the field to `Default::default()`, so those fields'
+///   types must implement `Default`.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(patch_apply)]
+///   struct User { /* ... */ }
+///
+///   user.apply_patch(user_patch);
+///   ```
+///
+/// - `patch_non_empty`: Generate
+///   `pub fn require_change(self) -> Result<Self, EmptyPatch>` on the Patch view, which
+///   fails if the patch is a no-op as reported by `NoopPatch::is_noop`. Use it to reject
+///   PATCH requests that would not change anything.
+///
+/// - `merge_patch_serialize`: With serde enabled, implement `Serialize` for the Patch view
+///   by hand, producing an [RFC 7396](https://www.rfc-editor.org/rfc/rfc7396) JSON Merge
+///   Patch document instead of the derived output: `Patch::Ignore` (and `None` of
+///   `patch = "replace"`, `PatchOpt::Ignore` of `patch = "tri"`) omits the key, an update
+///   sets it to the value, and `Patch::Update(None)` of `patch = "optional"` (or
+///   `PatchOpt::SetNull`) writes `null` to delete it. Nested patches are written as nested
+///   documents, including ones that ignore all of their fields. Keys follow `rename_all`,
+///   `patch_rename_all` and field-level `#[serde(rename = "...")]`, while other serde field
+///   attributes only affect deserialization. The Patch view still derives `Deserialize`.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, merge_patch_serialize)]
+///   struct User { /* ... */ }
+///
+///   // {"name":"Alice","email":null}
+///   let json = serde_json::to_string(&user_patch)?;
+///   ```
+///
+/// - `patch_columns`: Generate a `{Model}PatchColumn` enum with a variant per Patch view
+///   field, named after the field in `PascalCase` (`Field0`, `Field1`, ... for tuple
+///   structs), and `pub fn set_columns(&self) -> Vec<{Model}PatchColumn>` on the Patch
+///   view, listing the columns the patch updates in field order, e.g. to build the `SET`
+///   clause of an `UPDATE` query. `PatchColumn::name` returns the field's Rust name. A
+///   nested patch field maps to its own variant only and counts as set whenever it is an
+///   update, even if the nested patch ignores all of its fields. Fields whose names map to
+///   the same variant, such as `foo` and `_foo`, are rejected.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(patch_columns)]
+///   struct User { /* display_name, email, ... */ }
+///
+///   let columns = user_patch.set_columns(); // e.g. [UserPatchColumn::DisplayName]
+///   ```
+///
+/// - `get_from`: Implement `From<Model>` for the Get view, converting every present field
+///   with `Into` like `create_from` does. `get = "optional"` fields are wrapped in `Some`.
+///   Fields renamed with `field = "..."` are read from the model's original field.
+///
+/// - `get_eq_model`: Generate `pub fn matches_model(&self, model: &{Model}) -> bool` on the
+///   Get view, e.g. to assert in tests that a view reflects the model it was built from. Each
+///   present field is compared with `==` against the model's field, so the field's Get type
+///   must implement `PartialEq` of the model's field type; this holds for plain values, while
+///   nested models need a manual impl. `get = "optional"` fields only take part when `Some`,
+///   and `get = "flatten_option"` fields match if both sides are `None`.
+///
+/// - `create_from`: Implement `From<Model>` for the Create view, e.g. to seed a create
+///   payload from an existing resource when cloning it. `create = "forbidden"` fields such
+///   as `id` are dropped, and `create = "optional"` fields are wrapped in `Some`. Every
+///   field is converted with `Into`, so nested models need `create_from` as well, and a
+///   field with a `create_type` override requires that type to implement `From` of the
+///   model's field type. Collections of nested models have no such `From` impl; use
+///   `into_view` for them instead.
+///
+/// - `patch_to_create`: Generate `TryFrom<{Model}Patch> for {Model}Create`, e.g. for
+///   upserts that create a resource from a patch. Each `create = "required"` field takes
+///   the patch field's updated value, converted through `TryFrom` so nested patches become
+///   nested Create views (with `patch_to_create` on the nested model). The conversion
+///   fails with a `MissingFieldsError` listing every required field that is not updated
+///   to a value, where updates to null count as missing. `create = "optional"` fields
+///   become `None` if they are not updated to a value. Every `create = "required"` field
+///   must be present in the Patch view.
+///
+/// - `patch_null_is_error`: With serde enabled, an explicit `null` for a
+///   `patch = "required"` field fails to deserialize instead of becoming `Patch::Ignore`,
+///   catching clients that send `null` expecting the value to be cleared. An absent field
+///   is still `Patch::Ignore`, and any other value is `Patch::Update`. If the field's type
+///   is itself an `Option<T>`, `null` is rejected as well; use `patch = "optional"` or
+///   `patch = "tri"` for fields that can be cleared.
+///
+/// - `patch_assert`: Generate `pub fn applies_to(&self, before: &User, after: &User) -> bool`
+///   on the Patch view, a testing aid that checks whether applying the patch to `before`
+///   yields `after`: every updated field must equal its value in `after`, and every
+///   ignored or forbidden field must be equal in `before` and `after`. Updates to null
+///   (`Patch::Update(None)`, `PatchOpt::SetNull`) are not checked. The field types must
+///   implement `PartialEq`, and each patch value type must implement `PartialEq` with
+///   the model's field type, which holds for all trivial types but not for nested views.
+///
+/// - `create_new`: Generate `pub fn new(...) -> Self` on the Create view, taking one
+///   argument per `create = "required"` field in field declaration order, named like
+///   the field (`field0`, `field1`, ... for tuple structs). All `create = "optional"`
+///   fields start out as `None`.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(create_new)]
+///   struct User {
+///       #[views(create = "required")]
+///       name: String,
+///       #[views(create = "optional")]
+///       email: String,
+///   }
+///
+///   let user = UserCreate::new("Alice".to_string()); // email: None
+///   ```
+///
+/// - `create_introspect`: Generate `pub fn missing_optionals(&self) -> Vec<&'static str>`
+///   on the Create view, listing the Rust names of all `create = "optional"` fields that
+///   are currently `None`. Useful for debugging incomplete payloads.
+///
+/// - `create_default`: Derive `Default` for the Create view, e.g. for config-style
+///   payloads that are filled in field by field. The Create view type of every
+///   `create = "required"` field must implement `Default`; otherwise the derive fails to
+///   compile. Optional fields default to `None`.
+///
+/// - `get_introspect`: Generate `pub fn present_optionals(&self) -> Vec<&'static str>`
+///   on the Get view, listing the Rust names of all `get = "optional"` fields that are
+///   currently `Some`. Useful for debugging sparse read payloads.
+///
+/// - `get_try_into`: Generate `TryFrom<{Model}Get> for {Model}`, converting each field
+///   back through `TryFrom`. A `get = "optional"` field that is `None` is reported as
+///   missing in the returned `MissingFieldsError`. Every field must be present in the
+///   Get view.
+///
+/// - `get_accessors`: Make the fields of the Get view private and generate a
+///   `pub const fn name(&self) -> &T` getter per field (`field0`, `field1`, ... for tuple
+///   structs), keeping API-boundary types read-only. `create_accessors` and
+///   `patch_accessors` do the same for the Create and Patch views. Outside the model's
+///   module, the view can then no longer be built with a struct literal; provide a
+///   constructor next to the model, or use `create_new` for the Create view.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(get_accessors)]
+///   struct User { /* ... */ }
+///
+///   let name: &String = user_get.name();
+///   ```
+///
+/// - `get_ref`: Generate `{Model}GetRef<'a>`, a `Copy` variant of the Get view that
+///   borrows its fields, along with `{Model}Get::as_ref_view` and
+///   `From<&'a {Model}Get>`. Fields of primitive numeric, `bool` and `char` types are
+///   copied; all other fields, including strings and nested views, become `&'a T`
+///   (`Option<&'a T>` for `get = "optional"`). With serde enabled, the borrowed view
+///   derives `Serialize` and serializes exactly like the owned one.
+///
+///   ```rust,ignore
+///   #[derive(Views)]
+///   #[views(serde, get_ref)]
+///   struct User { /* ... */ }
+///
+///   let json = serde_json::to_string(&user_get.as_ref_view())?;
+///   ```
+///
 /// # Field Attributes
 ///
 /// Each field can be independently configured for each view mode using `#[views(...)]`:
@@ -149,6 +737,11 @@ struct ViewsField {
 /// Controls how the field appears in the `{Model}Get` type:
 /// - `"required"` (default): Field is always present with its view type
 /// - `"optional"`: Field is wrapped in `Option<T>`
+/// - `"flatten_option"`: For a field of type `Option<T>`, the field is a single
+///   `Option<T>` instead of the `Option<Option<T>>` that `"optional"` would produce.
+///   An absent value and a `None` value both become `None`, so the view cannot tell
+///   whether the value was left out or is actually unset. With `get_try_into`, `None`
+///   therefore converts back to `None` rather than being reported as missing.
 /// - `"forbidden"`: Field is excluded from the Get view
 ///
 /// ## Create Mode (`create = "policy"`)
@@ -159,84 +752,299 @@ struct ViewsField {
 ///   `skip_serializing_if` attributes when serde is enabled
 /// - `"forbidden"`: Field is excluded from the Create view
 ///
-/// ## Patch Mode (`patch = "policy"`)
+/// A `"required"` field may additionally be marked `create_serde_default`. It then keeps
+/// its plain type but gets `#[serde(default)]` when serde is enabled, so an omitted value
+/// deserializes as `Default::default()` instead of failing. The field's Create view type
+/// must implement `Default`.
 ///
-/// Controls how the field appears in the `{Model}Patch` type:
-/// - `"patch"` (default): Field is wrapped in `Patch<T>`, allowing explicit ignore/update
-/// - `"optional"`: Field is wrapped in `Patch<Option<T>>`
-/// - `"forbidden"`: Field is excluded from the Patch view
+/// ```rust,ignore
+/// #[derive(Views)]
+/// #[views(serde)]
+/// struct User {
+///     #[views(create = "required", create_serde_default)]
+///     roles: Vec<String>,
+/// }
+/// ```
 ///
-/// # Examples
+/// With serde enabled, `create_deserialize_with = "path"` adds
+/// `#[serde(deserialize_with = "path")]` to the field in the Create view only, e.g. to trim
+/// input strings. For an `"optional"` field, the function must produce the `Option<T>`.
 ///
-/// ## Basic Usage
+/// ```rust,ignore
+/// #[derive(Views)]
+/// #[views(serde)]
+/// struct User {
+///     #[views(create = "required", create_deserialize_with = "trimmed")]
+///     name: String,
+/// }
+/// ```
+///
+/// An `"optional"` field may use `create_sentinel = "path"` for legacy payloads that
+/// encode absence as a sentinel value such as `-1` or `""`. The field then keeps its plain
+/// type instead of being wrapped in `Option`, and the function at `path` supplies the
+/// sentinel: with serde enabled as `#[serde(default = "path")]`, and for `create_new` and
+/// `patch_to_create` when no value is given. Such fields are not reported by
+/// `missing_optionals`.
 ///
 /// ```rust,ignore
-/// use model_views::Views;
+/// fn no_limit() -> i32 { -1 }
 ///
 /// #[derive(Views)]
-/// struct Article {
-///     // ID only appears in Get view (auto-generated, can't be set)
-///     #[views(get = "required", create = "forbidden", patch = "forbidden")]
+/// #[views(serde)]
+/// struct Quota {
+///     #[views(create = "optional", create_sentinel = "no_limit")]
+///     limit: i32,
+/// }
+/// ```
+///
+/// A `"forbidden"` field may instead be marked `create_accept_forbidden`, e.g. while
+/// clients are migrated away from sending it. With serde enabled, the Create view then
+/// keeps the field as a `serde::de::IgnoredAny`, so a value sent for it is parsed and
+/// dropped rather than rejected by `deny_unknown_fields`. When constructing the view in
+/// code, set the field to `IgnoredAny`.
+///
+/// ```rust,ignore
+/// #[derive(Views)]
+/// #[views(serde)]
+/// struct User {
+///     #[views(create = "forbidden", create_accept_forbidden)]
 ///     id: u64,
-///     
-///     // Title is required everywhere
-///     #[views(get = "required", create = "required", patch = "required")]
-///     title: String,
-///     
-///     // Published status can be patched
-///     #[views(get = "required", create = "optional", patch = "required")]
-///     published: bool,
 /// }
 /// ```
 ///
-/// This generates:
-/// - `ArticleGet { id: u64, title: String, published: bool }`
-/// - `ArticleCreate { title: String, published: Option<bool> }`
-/// - `ArticlePatch { title: Patch<String>, published: Patch<bool> }`
+/// ## Patch Mode (`patch = "policy"`)
 ///
-/// ## With Serde Support
+/// Controls how the field appears in the `{Model}Patch` type:
+/// - `"required"` or `"patch"` (default): Field is wrapped in `Patch<T>`, allowing explicit
+///   ignore/update
+/// - `"optional"`: Field is wrapped in `Patch<Option<T>>`. With serde enabled, an absent
+///   field is `Ignore` and `null` is `Update(None)`
+/// - `"replace"`: Field is a plain `Option<T>`, with serde's `default` and
+///   `skip_serializing_if` attributes when serde is enabled. This mirrors the common
+///   "absent or `null` means no change" convention, but it cannot distinguish between
+///   leaving a value untouched and explicitly clearing it. Prefer `Patch<T>` unless you
+///   need compatibility with an existing API.
+/// - `"tri"`: Field is a `PatchOpt<T>` with the variants `Ignore`, `SetNull` and
+///   `SetValue(T)`, naming the three states of a `Patch<Option<T>>` explicitly. With
+///   serde enabled, an absent field is `Ignore`, `null` is `SetNull` and any other value
+///   is `SetValue`.
+/// - `"forbidden"`: Field is excluded from the Patch view
+///
+/// With serde enabled, each Patch view field is skipped when serializing if its patch is
+/// a no-op. `patch_skip_if = "path"` replaces that check with the function at `path`,
+/// which receives a reference to the field's value in the Patch view and returns `bool`,
+/// like serde's `skip_serializing_if`.
 ///
 /// ```rust,ignore
 /// #[derive(Views)]
 /// #[views(serde)]
 /// struct User {
-///     #[views(get = "required", create = "forbidden", patch = "forbidden")]
-///     id: u64,
-///     #[views(get = "required", create = "required", patch = "required")]
+///     #[views(patch = "patch", patch_skip_if = "Patch::is_ignore")]
 ///     name: String,
 /// }
 /// ```
 ///
-/// The generated types will have appropriate `Serialize`/`Deserialize` derives.
+/// ## Type Overrides (`opaque`, `get_type`, `create_type`, `patch_type`, `via`)
 ///
-/// ## Generic Types
+/// By default a field's view type is its `<T as View<M>>::Type` projection, which the
+/// policy then wraps (e.g. in `Option` or `Patch`). The `opaque` flag uses the field type
+/// `T` verbatim in all views instead, so the type needs no `View` impl. This is the way to
+/// support trait-object fields such as `Box<dyn Shape>`, for which no `View` impl can
+/// exist. `get_type = "Type"` (likewise `create_type` and `patch_type`) overrides the view
+/// type for a single mode and takes precedence over `opaque`. Opaque fields still follow
+/// their policies, and with `merge` a later update replaces them wholesale, so their type
+/// needs no `PatchMerge` impl either.
 ///
-/// The macro supports generic parameters:
+/// `via = "Type"` overrides the type in all three views at once, e.g. to represent another
+/// crate's newtype `Id` without a `View` impl as a plain `u64`. Generated conversions such
+/// as `get_from`, `create_from`, `into_view` and `patch_apply` convert overridden fields
+/// with `Into`, so they require `From` impls between the field type and the substitute in
+/// the respective direction. A `get_type`, `create_type` or `patch_type` given alongside
+/// takes precedence for its view.
 ///
 /// ```rust,ignore
 /// #[derive(Views)]
-/// struct Container<T> {
-///     #[views(get = "required", create = "required", patch = "required")]
-///     value: T,
+/// struct Drawing {
+///     #[views(opaque, create = "required", patch = "forbidden")]
+///     shape: Box<dyn Shape>,
+///     #[views(get_type = "String", create = "forbidden", patch = "forbidden")]
+///     checksum: Checksum,
 /// }
 /// ```
 ///
-/// # Panics
-///
-/// The macro will panic at compile time if:
-/// - Applied to an enum or union (only structs with named fields are supported)
-/// - An unknown policy value is used (e.g., `get = "invalid"`)
-/// - The `crate` attribute contains an invalid path
+/// ## Aliases (`alias = "name"`)
 ///
-/// # Implementation Details
+/// With serde enabled, adds `#[serde(alias = "name")]` to the field in the Create and
+/// Patch views, which are the ones being deserialized, e.g. to keep accepting a field's
+/// legacy name after renaming it. The attribute may be repeated for several aliases.
 ///
-/// - View types only include fields that have at least one non-forbidden policy
-/// - If all fields are forbidden for a view mode, that view type is still generated
-///   (as an empty struct)
-/// - Generated types preserve the original struct's visibility and generic parameters
-/// - Non-`#[views(...)]` attributes from the original struct are copied to generated types
-/// - When serde is enabled, optional create fields get `#[serde(default, skip_serializing_if = "Option::is_none")]`
-#[proc_macro_derive(Views, attributes(views, view))]
+/// ```rust,ignore
+/// #[derive(Views)]
+/// #[views(serde)]
+/// struct User {
+///     #[views(alias = "userName", alias = "user_name")]
+///     name: String,
+/// }
+/// ```
+///
+/// ## Redaction (`redact`)
+///
+/// Marks a field as sensitive, e.g. a password or token. Every view containing a redacted
+/// field gets a `Debug` impl that prints `***` in place of the field's value, so secrets
+/// do not leak into logs; all other fields are printed normally and must implement
+/// `Debug`. Such views must not list `Debug` in `get_derive` and friends, which is dropped
+/// from the derive list in favor of the generated impl.
+///
+/// ```rust,ignore
+/// #[derive(Views)]
+/// struct Account {
+///     name: String,
+///     #[views(redact, get = "forbidden")]
+///     password: String,
+/// }
+///
+/// // AccountCreate { name: "alice", password: *** }
+/// println!("{create:?}");
+/// ```
+///
+/// ## Renaming (`field = "name"`)
+///
+/// Uses `name` as the field's Rust identifier in all generated views, while the model
+/// keeps its own name. Generated conversions such as `get_try_into` map between the two
+/// names. With serde enabled, the new name is also the serialized key unless overridden
+/// with `#[serde(rename = "...")]`.
+///
+/// ```rust,ignore
+/// #[derive(Views)]
+/// struct User {
+///     #[views(field = "display_name")]
+///     dsp_nm: String,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ## Basic Usage
+///
+/// ```rust,ignore
+/// use model_views::Views;
+///
+/// #[derive(Views)]
+/// struct Article {
+///     // ID only appears in Get view (auto-generated, can't be set)
+///     #[views(get = "required", create = "forbidden", patch = "forbidden")]
+///     id: u64,
+///     
+///     // Title is required everywhere
+///     #[views(get = "required", create = "required", patch = "required")]
+///     title: String,
+///     
+///     // Published status can be patched
+///     #[views(get = "required", create = "optional", patch = "required")]
+///     published: bool,
+/// }
+/// ```
+///
+/// This generates:
+/// - `ArticleGet { id: u64, title: String, published: bool }`
+/// - `ArticleCreate { title: String, published: Option<bool> }`
+/// - `ArticlePatch { title: Patch<String>, published: Patch<bool> }`
+///
+/// ## With Serde Support
+///
+/// ```rust,ignore
+/// #[derive(Views)]
+/// #[views(serde)]
+/// struct User {
+///     #[views(get = "required", create = "forbidden", patch = "forbidden")]
+///     id: u64,
+///     #[views(get = "required", create = "required", patch = "required")]
+///     name: String,
+/// }
+/// ```
+///
+/// The generated types will have appropriate `Serialize`/`Deserialize` derives.
+///
+/// ## Generic Types
+///
+/// The macro supports generic parameters:
+///
+/// ```rust,ignore
+/// #[derive(Views)]
+/// struct Container<T> {
+///     #[views(get = "required", create = "required", patch = "required")]
+///     value: T,
+/// }
+/// ```
+///
+/// Const generics are forwarded as well, e.g. `BufferGet<16>` for
+/// `struct Buffer<const N: usize> { data: [u8; N] }`.
+///
+/// The model's where clause is kept on every generated type and impl. For each view, a
+/// field whose type is a bare type parameter adds a bound like `T: View<ViewModeGet>` to
+/// it; bounds for other generic field types, such as `Vec<T>`, must be written on the
+/// model if they are needed.
+///
+/// # Panics
+///
+/// The macro will panic at compile time if:
+/// - Applied to an enum or union (only structs with named fields are supported)
+/// - Applied to a tuple struct without `transparent`, or with `transparent` to a struct
+///   that does not have exactly one field
+/// - An unknown policy value is used (e.g., `get = "invalid"`)
+/// - `get = "flatten_option"` is used on a field that is not an `Option<T>`
+/// - `create_serde_default` is used on a field that is not `create = "required"`
+/// - `create_accept_forbidden` is used on a field that is not `create = "forbidden"`
+/// - A `*_type` or `via` attribute does not contain a valid type
+/// - `create_sentinel` is used on a field that is not `create = "optional"`
+/// - `create_deserialize_with`, `create_sentinel` or `patch_skip_if` does not contain a
+///   valid path
+/// - `field = "..."` is not a valid identifier or is used on a tuple struct field
+/// - The `crate` attribute contains an invalid path
+/// - The `base_name` attribute is not a valid identifier
+/// - A `*_validate` attribute is given without its matching `*_validate_error` (or vice versa)
+/// - `patch_normalize` does not contain a valid path
+/// - `get_try_into` is set while a field is forbidden in the Get view
+/// - `runtime_dispatch` is set without `serde`, or while any view has no fields
+/// - `merge_patch_serialize` is set without `serde`, on a tuple struct, with a
+///   `#[serde(flatten)]` Patch field, or with an unknown `rename_all` rule
+/// - `create_xor` is set without `serde`, lists fewer than two fields, or names a field that
+///   is not `create = "optional"` (or uses `create_sentinel`)
+/// - `patch_to_create` is set while a `create = "required"` field is forbidden in the
+///   Patch view, or while the Create or Patch view has no fields
+/// - `get_from`, `get_eq_model`, `create_validate`, `create_from`, `patch_validate`,
+///   `patch_normalize`, `patch_assert`, `patch_apply`, `patch_non_empty` or `patch_columns`
+///   is set while the respective view has no fields
+///
+/// # Implementation Details
+///
+/// - Each generated type has a `FIELD_COUNT` constant holding its number of fields
+/// - View types only include fields that have at least one non-forbidden policy
+/// - If all fields are forbidden for a view mode, that view type is still generated
+///   (as an empty struct)
+/// - Generated types preserve the original struct's visibility and generic parameters
+/// - Non-`#[views(...)]` attributes from the original struct are copied to generated types,
+///   except for `#[repr(...)]`, which is only copied with `#[views(forward_repr)]`
+/// - Doc comments on the original fields are copied to the generated fields, so generated
+///   types stay clean under `#![deny(missing_docs)]` when the model is documented
+/// - With serde enabled, type-independent `#[serde(...)]` attributes on the original fields
+///   are copied to the generated fields as well
+/// - With serde and the `json` feature of `model_views` enabled, Get views convert into
+///   `serde_json::Value` via `From`; the conversion panics if serialization fails, which
+///   can only happen for maps with non-string keys
+/// - Field types are emitted as `<T as View<M>>::Type` projections; an `optional` policy
+///   on a field whose view type is itself an `Option` therefore yields `Option<Option<_>>`
+///   (use `get = "flatten_option"` to avoid this)
+/// - When serde is enabled, optional create fields get `#[serde(default, skip_serializing_if = "Option::is_none")]`
+/// - When serde is enabled, every Patch view field gets `#[serde(default)]`, so an omitted
+///   field deserializes as `Ignore` (or `None` for `"replace"`); transparent Patch views
+///   deserialize `null` as `Ignore` instead
+/// - When serde is enabled, the Create view type of every present create field is asserted
+///   to implement `Deserialize`, so a missing impl is reported at the field rather than
+///   inside the serde derive; models with type parameters are left to the bounds serde
+///   infers, fields with `create_deserialize_with` to their deserializer, and fields marked
+///   `#[serde(skip)]` or `#[serde(skip_deserializing)]` are not checked
+#[proc_macro_derive(Views, attributes(views, view, serde))]
 #[allow(clippy::missing_panics_doc,clippy::cognitive_complexity,clippy::too_many_lines)]
 pub fn derive_views(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -249,17 +1057,114 @@ pub fn derive_views(input: TokenStream) -> TokenStream {
     };
 
     let with_serde = meta.serde.unwrap_or(false);
+    let with_get_try_into = meta.get_try_into.unwrap_or(false);
+    let with_get_ref = meta.get_ref.unwrap_or(false);
+    let with_create_introspect = meta.create_introspect.unwrap_or(false);
+    let with_get_introspect = meta.get_introspect.unwrap_or(false);
+    let with_patch_assert = meta.patch_assert.unwrap_or(false);
+    let with_create_new = meta.create_new.unwrap_or(false);
+    let with_create_default = meta.create_default.unwrap_or(false);
+    let with_patch_null_is_error = meta.patch_null_is_error.unwrap_or(false);
+    let with_transparent = meta.transparent.unwrap_or(false);
+    let with_merge = meta.merge.unwrap_or(false);
+    let with_forward_repr = meta.forward_repr.unwrap_or(false);
+    let with_patch_apply = meta.patch_apply.unwrap_or(false);
+    let with_into_view = meta.into_view.unwrap_or(false);
+    let with_runtime_dispatch = meta.runtime_dispatch.unwrap_or(false);
+    assert!(
+        !with_runtime_dispatch || with_serde,
+        "#[views(runtime_dispatch)] requires #[views(serde)]"
+    );
+    let with_get_from = meta.get_from.unwrap_or(false);
+    let with_create_from = meta.create_from.unwrap_or(false);
+    let with_get_eq_model = meta.get_eq_model.unwrap_or(false);
+    let with_skip_serializing_none = meta.skip_serializing_none.unwrap_or(false);
+    let with_patch_non_empty = meta.patch_non_empty.unwrap_or(false);
+    let with_patch_columns = meta.patch_columns.unwrap_or(false);
+    let with_merge_patch_serialize = meta.merge_patch_serialize.unwrap_or(false);
+    assert!(
+        !with_merge_patch_serialize || with_serde,
+        "#[views(merge_patch_serialize)] requires #[views(serde)]"
+    );
+    let patch_rename_all = meta
+        .patch_rename_all
+        .as_deref()
+        .or(meta.rename_all.as_deref());
+    let with_patch_to_create = meta.patch_to_create.unwrap_or(false);
+
+    let create_validate = validate_hook(
+        "create",
+        meta.create_validate.as_deref(),
+        meta.create_validate_error.as_deref(),
+    );
+    let patch_validate = validate_hook(
+        "patch",
+        meta.patch_validate.as_deref(),
+        meta.patch_validate_error.as_deref(),
+    );
+    let patch_normalize: Option<syn::Path> = meta
+        .patch_normalize
+        .as_deref()
+        .map(|s| syn::parse_str(s).expect("valid path in #[views(patch_normalize = \"...\")]"));
 
     let name = &meta.ident;
-    let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+    let decl_generics = struct_generics(&meta.generics);
+    let type_params: Vec<_> = meta
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+    // `View` bounds of the generic field types, added to the model's where clause
+    let mut view_bounds: Vec<syn::WherePredicate> = Vec::new();
 
-    let create_ident = format_ident!("{name}Create");
-    let read_ident = format_ident!("{name}Get");
-    let patch_ident = format_ident!("{name}Patch");
+    // View types are named after the model unless `base_name` overrides the prefix
+    let base_name = meta.base_name.as_deref().map_or_else(
+        || name.unraw(),
+        |s| {
+            syn::parse_str::<syn::Ident>(s)
+                .unwrap_or_else(|_| panic!("valid identifier in #[views(base_name = \"{s}\")]"))
+                .unraw()
+        },
+    );
+    let create_ident = format_ident!("{base_name}Create");
+    let read_ident = format_ident!("{base_name}Get");
+    let patch_ident = format_ident!("{base_name}Patch");
 
     let mut create_fields = Vec::new();
+    let mut create_optional_members = Vec::new();
+    let mut create_new_args = Vec::new();
+    let mut create_new_inits = Vec::new();
+    let mut create_optional_names = Vec::new();
+    let mut create_deserialize_checks = Vec::new();
+    let mut create_deserialize_tys = Vec::new();
+    let mut get_optional_members = Vec::new();
+    let mut get_optional_names = Vec::new();
     let mut read_fields = Vec::new();
+    let mut read_ref_fields = Vec::new();
+    let mut read_ref_exprs = Vec::new();
     let mut patch_fields = Vec::new();
+    let mut patch_noops = Vec::new();
+    let mut patch_noop_fns = Vec::new();
+    let mut patch_columns: Vec<(syn::Ident, String, proc_macro2::TokenStream)> = Vec::new();
+    let mut merge_patch_entries = Vec::new();
+    let mut merge_patch_bounds: Vec<syn::WherePredicate> = Vec::new();
+    let mut patch_merges = Vec::new();
+    let mut patch_applies = Vec::new();
+    let mut patch_checks = Vec::new();
+
+    // Field bindings for the Get -> model conversion
+    let mut get_try_members = Vec::new();
+    let mut get_try_idents = Vec::new();
+    let mut get_try_bindings = Vec::new();
+    let mut into_get_inits = Vec::new();
+    let mut into_create_inits = Vec::new();
+    let mut get_from_inits = Vec::new();
+    let mut create_from_inits = Vec::new();
+    let mut get_eq_checks = Vec::new();
+    let mut into_patch_inits = Vec::new();
+    let mut patch_to_create_bindings = Vec::new();
+    let mut patch_to_create_idents = Vec::new();
+    let mut patch_to_create_members = Vec::new();
 
     // Track whether a given mode actually has any fields
     let mut has_get = false;
@@ -272,162 +1177,2035 @@ pub fn derive_views(input: TokenStream) -> TokenStream {
     let mv_patch = quote!(#crate_path::ViewModePatch);
     let mv_patch_t = quote!(#crate_path::Patch);
 
-    if let darling::ast::Data::Struct(ds) = &meta.data {
-        for f in &ds.fields {
-            let ident = f.ident.clone().expect("named fields only");
-            let fty = &f.ty;
+    let darling::ast::Data::Struct(ds) = &meta.data else {
+        panic!("#[derive(Views)] supports struct with named fields only");
+    };
+    let style = ds.style;
+    if with_transparent {
+        assert!(
+            ds.fields.len() == 1,
+            "#[views(transparent)] requires a struct with exactly one field"
+        );
+    } else {
+        assert!(
+            style != darling::ast::Style::Tuple,
+            "#[derive(Views)] supports struct with named fields only; \
+             use #[views(transparent)] for single-field newtypes"
+        );
+    }
 
-            // policies with defaults
-            let get_p = f.get.as_deref().unwrap_or("required");
-            let crt_p = f.create.as_deref().unwrap_or("required");
-            let patch_p = f.patch.as_deref().unwrap_or("required");
+    // serde rejects `deny_unknown_fields` in combination with flattened fields
+    let with_flatten = ds
+        .fields
+        .iter()
+        .any(|f| f.attrs.iter().any(is_serde_flatten));
 
-            // ---- GET / READ ----
-            match get_p {
-                "required" => {
-                    has_get = true;
-                    read_fields.push(quote! { pub #ident: <#fty as #mv_view<#mv_get>>::Type, });
+    for (index, f) in ds.fields.iter().enumerate() {
+        let f = &f.clone().resolve_via();
+        let model_member = f
+            .ident
+            .clone()
+            .map_or_else(|| syn::Member::Unnamed(index.into()), syn::Member::Named);
+        let binding = f
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("__field{index}"));
+        // The field's name in the generated views, which `field = "..."` may override
+        let view_ident =
+            match &f.field {
+                Some(name) => {
+                    assert!(
+                        f.ident.is_some(),
+                        "#[views(field = \"...\")] requires a named field"
+                    );
+                    Some(syn::parse_str::<syn::Ident>(name).unwrap_or_else(|_| {
+                        panic!("valid identifier in #[views(field = \"{name}\")]")
+                    }))
                 }
-                "optional" => {
-                    has_get = true;
-                    read_fields.push(quote! {
-                        pub #ident: ::core::option::Option<<#fty as #mv_view<#mv_get>>::Type>,
+                None => f.ident.clone(),
+            };
+        let member = view_ident
+            .clone()
+            .map_or_else(|| model_member.clone(), syn::Member::Named);
+        let ident_name = view_ident
+            .as_ref()
+            .map_or_else(|| index.to_string(), |ident| ident.unraw().to_string());
+        let fty = &f.ty;
+        // The field's type in each view before the policy wraps it, which is its `View`
+        // projection unless the field is opaque or the type is overridden
+        let opaque = f.opaque.unwrap_or(false);
+        let view_ty = |ty: &Type, mode: &proc_macro2::TokenStream, kind: &str, type_override| {
+            match type_override {
+                Some(type_override) => {
+                    let ty: Type = syn::parse_str(type_override).unwrap_or_else(|_| {
+                        panic!("valid type in #[views({kind}_type = \"{type_override}\")]")
                     });
+                    ty.into_token_stream()
                 }
-                "forbidden" => {}
-                other => panic!("unknown get policy: {other}"),
+                None if opaque => ty.into_token_stream(),
+                None => quote! { <#ty as #mv_view<#mode>>::Type },
             }
+        };
+        let get_ty = view_ty(fty, &mv_get, "get", f.get_type.as_deref());
+        let create_ty = view_ty(fty, &mv_create, "create", f.create_type.as_deref());
+        let patch_ty = view_ty(fty, &mv_patch, "patch", f.patch_type.as_deref());
+        // Docs are always forwarded, serde attributes only if the views derive serde and
+        // only as far as they do not depend on the field's type
+        let field_attrs: Vec<_> = f
+            .attrs
+            .iter()
+            .filter_map(|attr| {
+                if !attr.path().is_ident("serde") {
+                    Some(attr.to_token_stream())
+                } else if with_serde {
+                    forwarded_serde_attr(attr)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let redacted = f.redact.unwrap_or(false);
+        let view_field =
+            |attrs: Vec<proc_macro2::TokenStream>, ty: proc_macro2::TokenStream| ViewField {
+                attrs: field_attrs.iter().cloned().chain(attrs).collect(),
+                member: member.clone(),
+                ty,
+                public: true,
+                redacted,
+            };
+        // Fields of the deserialized Create and Patch views also accept their aliases
+        let aliases: Vec<_> = if with_serde && !with_transparent {
+            f.alias
+                .iter()
+                .map(|alias| quote! { #[serde(alias = #alias)] })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let de_field = |mut attrs: Vec<proc_macro2::TokenStream>, ty| {
+            attrs.extend(aliases.iter().cloned());
+            view_field(attrs, ty)
+        };
+        // `Option` fields of the Get views, see `skip_serializing_none`
+        let skip_none = || {
+            if with_serde && with_skip_serializing_none && !with_transparent {
+                vec![quote! { #[serde(skip_serializing_if = "Option::is_none")] }]
+            } else {
+                vec![]
+            }
+        };
+        let model_option = option_inner(fty).is_some();
 
-            // ---- CREATE ----
-            match crt_p {
-                "required" => {
-                    has_create = true;
-                    create_fields.push(quote! {
-                        pub #ident: <#fty as #mv_view<#mv_create>>::Type,
-                    });
+        // policies with defaults
+        let get_p = f.get.as_deref().unwrap_or("required");
+        let crt_p = f.create.as_deref().unwrap_or("required");
+        // `patch = "patch"` is an alias of the default policy
+        let patch_p = match f.patch.as_deref().unwrap_or("required") {
+            "patch" => "required",
+            other => other,
+        };
+
+        // Fields of a bare type parameter need a `View` bound to be projected; other generic
+        // types are left alone, since a bound would hide their impls from normalization
+        if !opaque {
+            let get_ty = if get_p == "flatten_option" {
+                option_inner(fty).unwrap_or(fty)
+            } else {
+                fty
+            };
+            let modes = [
+                (get_p != "forbidden" && f.get_type.is_none()).then_some((get_ty, &mv_get)),
+                (crt_p != "forbidden" && f.create_type.is_none()).then_some((fty, &mv_create)),
+                (patch_p != "forbidden" && f.patch_type.is_none()).then_some((fty, &mv_patch)),
+            ];
+            for (ty, mode) in modes.into_iter().flatten() {
+                let bound: syn::WherePredicate = syn::parse_quote! { #ty: #mv_view<#mode> };
+                if is_type_param(ty, &type_params) && !view_bounds.contains(&bound) {
+                    view_bounds.push(bound);
                 }
-                "optional" => {
-                    has_create = true;
-                    if with_serde {
-                        create_fields.push(quote! {
-                            #[serde(default, skip_serializing_if = "Option::is_none")]
-                        });
+            }
+        }
+
+        // ---- GET / READ ----
+        // Borrowed views keep `Copy` primitives by value, unless the Get type is overridden
+        // (also through `via`) and no longer the model's primitive
+        match get_p {
+            "required" => {
+                has_get = true;
+                let attrs = if model_option && f.get_type.is_none() {
+                    skip_none()
+                } else {
+                    vec![]
+                };
+                read_fields.push(view_field(attrs.clone(), quote! { #get_ty }));
+                if f.get_type.is_none() && is_copy_primitive(fty) {
+                    read_ref_fields.push(view_field(attrs, quote! { #fty }));
+                    read_ref_exprs.push(quote! { self.#member });
+                } else {
+                    read_ref_fields.push(view_field(attrs, quote! { &'a #get_ty }));
+                    read_ref_exprs.push(quote! { &self.#member });
+                }
+                get_try_bindings.push(quote! {
+                    let #binding = __missing.nested(
+                        #ident_name,
+                        <#fty as ::core::convert::TryFrom<_>>::try_from(__view.#member),
+                    );
+                });
+            }
+            "optional" => {
+                has_get = true;
+                read_fields.push(view_field(
+                    skip_none(),
+                    quote! { ::core::option::Option<#get_ty> },
+                ));
+                if f.get_type.is_none() && is_copy_primitive(fty) {
+                    read_ref_fields.push(view_field(
+                        skip_none(),
+                        quote! { ::core::option::Option<#fty> },
+                    ));
+                    read_ref_exprs.push(quote! { self.#member });
+                } else {
+                    read_ref_fields.push(view_field(
+                        skip_none(),
+                        quote! { ::core::option::Option<&'a #get_ty> },
+                    ));
+                    read_ref_exprs.push(quote! { self.#member.as_ref() });
+                }
+                get_optional_members.push(member.clone());
+                get_optional_names.push(ident_name.clone());
+                get_try_bindings.push(quote! {
+                    let #binding = match __view.#member {
+                        ::core::option::Option::Some(value) => __missing.nested(
+                            #ident_name,
+                            <#fty as ::core::convert::TryFrom<_>>::try_from(value),
+                        ),
+                        ::core::option::Option::None => __missing.require(#ident_name, None),
+                    };
+                });
+            }
+            "flatten_option" => {
+                let inner = option_inner(fty).unwrap_or_else(|| {
+                    panic!("get = \"flatten_option\" requires an `Option<T>` field, but `{ident_name}` is not")
+                });
+                let get_ty = view_ty(inner, &mv_get, "get", f.get_type.as_deref());
+                has_get = true;
+                read_fields.push(view_field(
+                    skip_none(),
+                    quote! { ::core::option::Option<#get_ty> },
+                ));
+                if f.get_type.is_none() && is_copy_primitive(inner) {
+                    read_ref_fields.push(view_field(
+                        skip_none(),
+                        quote! { ::core::option::Option<#inner> },
+                    ));
+                    read_ref_exprs.push(quote! { self.#member });
+                } else {
+                    read_ref_fields.push(view_field(
+                        skip_none(),
+                        quote! { ::core::option::Option<&'a #get_ty> },
+                    ));
+                    read_ref_exprs.push(quote! { self.#member.as_ref() });
+                }
+                // `None` is a legitimate model value here, so it is never reported as missing
+                get_try_bindings.push(quote! {
+                    let #binding = match __view.#member {
+                        ::core::option::Option::Some(value) => __missing
+                            .nested(
+                                #ident_name,
+                                <#inner as ::core::convert::TryFrom<_>>::try_from(value),
+                            )
+                            .map(::core::option::Option::Some),
+                        ::core::option::Option::None => {
+                            ::core::option::Option::Some(::core::option::Option::None)
+                        }
+                    };
+                });
+            }
+            "forbidden" if with_get_try_into => panic!(
+                "#[views(get_try_into)] requires every field to be present in the Get view, \
+                 but `{ident_name}` is forbidden"
+            ),
+            "forbidden" => {}
+            other => panic!("unknown get policy: {other}"),
+        }
+        get_try_members.push(model_member.clone());
+        get_try_idents.push(binding.clone());
+
+        // ---- CREATE ----
+        let create_serde_default = f.create_serde_default.unwrap_or(false);
+        assert!(
+            !create_serde_default || crt_p == "required",
+            "#[views(create_serde_default)] requires create = \"required\", \
+             but `{ident_name}` is {crt_p}"
+        );
+        let create_accept_forbidden = f.create_accept_forbidden.unwrap_or(false);
+        assert!(
+            !create_accept_forbidden || crt_p == "forbidden",
+            "#[views(create_accept_forbidden)] requires create = \"forbidden\", \
+             but `{ident_name}` is {crt_p}"
+        );
+        let create_sentinel = f.create_sentinel.as_ref().map(|path| {
+            assert!(
+                crt_p == "optional",
+                "#[views(create_sentinel)] requires create = \"optional\", \
+                 but `{ident_name}` is {crt_p}"
+            );
+            let sentinel = syn::parse_str::<syn::Path>(path)
+                .unwrap_or_else(|_| panic!("valid path in #[views(create_sentinel = \"{path}\")]"));
+            (path, sentinel)
+        });
+        let sentinel = create_sentinel.is_some();
+        let create_deserialize_with = f.create_deserialize_with.as_ref().map(|path| {
+            syn::parse_str::<syn::Path>(path).unwrap_or_else(|_| {
+                panic!("valid path in #[views(create_deserialize_with = \"{path}\")]")
+            });
+            quote! { #[serde(deserialize_with = #path)] }
+        });
+        // A custom deserializer builds the value, and serde leaves skipped fields at their
+        // default, so neither needs the Create type to implement `Deserialize`
+        let check_deserialize = create_deserialize_with.is_none()
+            && !f
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("serde"))
+                .any(|attr| {
+                    serde_items(attr)
+                        .iter()
+                        .any(|item| item_is(item, SKIP_DESERIALIZING))
+                });
+        // Types with generic arguments may contain this very view, e.g. `Option<Box<Self>>`,
+        // and bounding the view on them would recurse
+        let bound_deserialize = check_deserialize
+            && f.create_type.as_deref().map_or_else(
+                || is_plain_path(fty),
+                |ty| syn::parse_str::<Type>(ty).is_ok_and(|ty| is_plain_path(&ty)),
+            );
+        match crt_p {
+            "required" => {
+                has_create = true;
+                let mut attrs = Vec::new();
+                if with_serde && create_serde_default {
+                    attrs.push(quote! { #[serde(default)] });
+                }
+                if with_serde {
+                    attrs.extend(create_deserialize_with);
+                }
+                create_fields.push(de_field(attrs, quote! { #create_ty }));
+                if check_deserialize {
+                    create_deserialize_checks.push(deserialize_check(fty, &create_ty));
+                    if bound_deserialize {
+                        create_deserialize_tys.push(create_ty.clone());
                     }
-                    create_fields.push(quote! {
-                        pub #ident: ::core::option::Option<<#fty as #mv_view<#mv_create>>::Type>,
+                }
+                let arg = view_ident
+                    .clone()
+                    .unwrap_or_else(|| format_ident!("field{index}"));
+                create_new_inits.push(quote! { #member: #arg });
+                create_new_args.push(quote! { #arg: #create_ty });
+            }
+            // An absent value is the sentinel rather than `None`
+            "optional" if sentinel => {
+                let (path, sentinel) = create_sentinel.as_ref().expect("checked by the guard");
+                has_create = true;
+                let mut attrs = Vec::new();
+                if with_serde {
+                    attrs.push(quote! { #[serde(default = #path)] });
+                    attrs.extend(create_deserialize_with);
+                }
+                create_fields.push(de_field(attrs, quote! { #create_ty }));
+                if check_deserialize {
+                    create_deserialize_checks.push(deserialize_check(fty, &create_ty));
+                    if bound_deserialize {
+                        create_deserialize_tys.push(create_ty.clone());
+                    }
+                }
+                create_new_inits.push(quote! { #member: #sentinel() });
+            }
+            "optional" => {
+                has_create = true;
+                let mut attrs = Vec::new();
+                if with_serde {
+                    attrs.push(quote! {
+                        #[serde(default, skip_serializing_if = "Option::is_none")]
                     });
+                    attrs.extend(create_deserialize_with);
+                }
+                create_fields.push(de_field(
+                    attrs,
+                    quote! { ::core::option::Option<#create_ty> },
+                ));
+                if check_deserialize {
+                    create_deserialize_checks.push(deserialize_check(fty, &create_ty));
+                    if bound_deserialize {
+                        create_deserialize_tys.push(create_ty.clone());
+                    }
                 }
-                "forbidden" => {}
-                other => panic!("unknown create policy: {other}"),
+                create_new_inits.push(quote! { #member: ::core::option::Option::None });
+                create_optional_members.push(member.clone());
+                create_optional_names.push(ident_name.clone());
+            }
+            // Keep the field under its serde name, but discard whatever value it holds
+            "forbidden" if with_serde && create_accept_forbidden => {
+                has_create = true;
+                create_fields.push(de_field(
+                    vec![quote! { #[serde(default)] }],
+                    quote! { ::serde::de::IgnoredAny },
+                ));
+                create_new_inits.push(quote! { #member: ::serde::de::IgnoredAny });
             }
+            "forbidden" => {}
+            other => panic!("unknown create policy: {other}"),
+        }
 
-            // ---- PATCH ----
-            match patch_p {
-                "required" => {
-                    has_patch = true;
-                    patch_fields.push(quote! {
-                        pub #ident: #mv_patch_t<<#fty as #mv_view<#mv_patch>>::Type>,
-                    });
+        // How `TryFrom<{Model}Patch> for {Model}Create` obtains the field from the patch,
+        // where only updates to a value count
+        if with_patch_to_create && (crt_p != "forbidden" || create_accept_forbidden) {
+            let value = match patch_p {
+                "required" => quote! {
+                    match __patch.#member {
+                        #mv_patch_t::Update(value) => ::core::option::Option::Some(value),
+                        #mv_patch_t::Ignore => ::core::option::Option::None,
+                    }
+                },
+                "optional" => quote! {
+                    match __patch.#member {
+                        #mv_patch_t::Update(value) => value,
+                        #mv_patch_t::Ignore => ::core::option::Option::None,
+                    }
+                },
+                "replace" => quote! { __patch.#member },
+                "tri" => quote! {
+                    match __patch.#member {
+                        #crate_path::PatchOpt::SetValue(value) => ::core::option::Option::Some(value),
+                        _ => ::core::option::Option::None,
+                    }
+                },
+                _ if crt_p == "required" => panic!(
+                    "#[views(patch_to_create)] requires every create = \"required\" field to be \
+                     present in the Patch view, but `{ident_name}` is forbidden"
+                ),
+                _ => quote! { ::core::option::Option::None },
+            };
+            let convert = quote! {
+                __missing.nested(
+                    #ident_name,
+                    <#create_ty as ::core::convert::TryFrom<_>>::try_from(value),
+                )
+            };
+            patch_to_create_bindings.push(match crt_p {
+                "optional" if sentinel => {
+                    let (_, sentinel) = create_sentinel.as_ref().expect("checked by the guard");
+                    quote! {
+                        let #binding = match #value {
+                            ::core::option::Option::Some(value) => #convert,
+                            ::core::option::Option::None => {
+                                ::core::option::Option::Some(#sentinel())
+                            }
+                        };
+                    }
                 }
-                "optional" => {
-                    has_patch = true;
-                    patch_fields.push(quote! {
-                        pub #ident: #mv_patch_t<::core::option::Option<<#fty as #mv_view<#mv_patch>>::Type>>,
+                "required" => quote! {
+                    let #binding = match #value {
+                        ::core::option::Option::Some(value) => #convert,
+                        ::core::option::Option::None => __missing.require(#ident_name, None),
+                    };
+                },
+                "optional" => quote! {
+                    let #binding = match #value {
+                        ::core::option::Option::Some(value) => {
+                            #convert.map(::core::option::Option::Some)
+                        }
+                        ::core::option::Option::None => {
+                            ::core::option::Option::Some(::core::option::Option::None)
+                        }
+                    };
+                },
+                _ => quote! {
+                    let #binding = ::core::option::Option::Some(::serde::de::IgnoredAny);
+                },
+            });
+            patch_to_create_idents.push(binding);
+            patch_to_create_members.push(member.clone());
+        }
+
+        // How `IntoView` converts the field, which recurses into nested models unless the
+        // field is opaque or its type is overridden
+        if with_into_view {
+            let convert =
+                |ty: &Type, mode: &proc_macro2::TokenStream, type_override: Option<&String>| {
+                    if type_override.is_some() {
+                        quote! { ::core::convert::Into::into(value) }
+                    } else if opaque {
+                        quote! { value }
+                    } else {
+                        quote! { <#ty as #crate_path::IntoView<#mode>>::into_view(value) }
+                    }
+                };
+            let value = quote! { __model.#model_member };
+            let get = convert(fty, &mv_get, f.get_type.as_ref());
+            match get_p {
+                "required" => into_get_inits.push(quote! { #member: { let value = #value; #get } }),
+                "optional" => into_get_inits.push(quote! {
+                    #member: { let value = #value; ::core::option::Option::Some(#get) }
+                }),
+                "flatten_option" => {
+                    let inner = option_inner(fty).expect("checked above");
+                    let get = convert(inner, &mv_get, f.get_type.as_ref());
+                    into_get_inits.push(quote! {
+                        #member: #value.map(|value| #get)
                     });
                 }
-                "forbidden" => {}
-                other => panic!("unknown patch policy: {other}"),
+                _ => {}
+            }
+            let create = convert(fty, &mv_create, f.create_type.as_ref());
+            match crt_p {
+                "required" => {
+                    into_create_inits.push(quote! { #member: { let value = #value; #create } });
+                }
+                "optional" if sentinel => {
+                    into_create_inits.push(quote! { #member: { let value = #value; #create } });
+                }
+                "optional" => into_create_inits.push(quote! {
+                    #member: { let value = #value; ::core::option::Option::Some(#create) }
+                }),
+                "forbidden" if with_serde && create_accept_forbidden => {
+                    into_create_inits.push(quote! { #member: ::serde::de::IgnoredAny });
+                }
+                _ => {}
+            }
+            let patch = convert(fty, &mv_patch, f.patch_type.as_ref());
+            let patch = match patch_p {
+                "required" => Some(quote! { #mv_patch_t::Update(#patch) }),
+                "optional" => Some(quote! {
+                    #mv_patch_t::Update(::core::option::Option::Some(#patch))
+                }),
+                "replace" => Some(quote! { ::core::option::Option::Some(#patch) }),
+                "tri" => Some(quote! { #crate_path::PatchOpt::SetValue(#patch) }),
+                _ => None,
+            };
+            if let Some(patch) = patch {
+                into_patch_inits.push(quote! { #member: { let value = #value; #patch } });
             }
         }
-    } else {
-        panic!("#[derive(Views)] supports struct with named fields only");
+
+        // How `From<Model>` for the Get and Create views obtains the field from the model,
+        // which keeps its original name under `field = "..."`
+        if with_get_from {
+            let value = quote! { ::core::convert::Into::into(__model.#model_member) };
+            match get_p {
+                "required" => get_from_inits.push(quote! { #member: #value }),
+                "optional" => get_from_inits.push(quote! {
+                    #member: ::core::option::Option::Some(#value)
+                }),
+                "flatten_option" => get_from_inits.push(quote! {
+                    #member: __model.#model_member.map(::core::convert::Into::into)
+                }),
+                _ => {}
+            }
+        }
+        if with_get_eq_model {
+            let model_value = quote! { __model.#model_member };
+            match get_p {
+                "required" => get_eq_checks.push(quote! { self.#member == #model_value }),
+                "optional" => get_eq_checks.push(quote! {
+                    self.#member.as_ref().is_none_or(|value| *value == #model_value)
+                }),
+                "flatten_option" => get_eq_checks.push(quote! {
+                    match (&self.#member, &#model_value) {
+                        (
+                            ::core::option::Option::Some(value),
+                            ::core::option::Option::Some(model),
+                        ) => value == model,
+                        (::core::option::Option::None, ::core::option::Option::None) => true,
+                        _ => false,
+                    }
+                }),
+                _ => {}
+            }
+        }
+        if with_create_from {
+            let value = quote! { ::core::convert::Into::into(__model.#model_member) };
+            match crt_p {
+                "required" => create_from_inits.push(quote! { #member: #value }),
+                "optional" if sentinel => create_from_inits.push(quote! { #member: #value }),
+                "optional" => create_from_inits.push(quote! {
+                    #member: ::core::option::Option::Some(#value)
+                }),
+                "forbidden" if with_serde && create_accept_forbidden => {
+                    create_from_inits.push(quote! { #member: ::serde::de::IgnoredAny });
+                }
+                _ => {}
+            }
+        }
+
+        // ---- PATCH ----
+        // How `applies_to` checks the field against the model states; updates to null are
+        // not checked since the model may have no representation for them
+        let unchanged = quote! {
+            ::core::cmp::PartialEq::eq(&before.#model_member, &after.#model_member)
+        };
+        let updated = quote! { ::core::cmp::PartialEq::eq(value, &after.#model_member) };
+        patch_checks.push(match patch_p {
+            "required" => quote! {
+                match &self.#member {
+                    #mv_patch_t::Ignore => #unchanged,
+                    #mv_patch_t::Update(value) => #updated,
+                }
+            },
+            "optional" => quote! {
+                match &self.#member {
+                    #mv_patch_t::Ignore => #unchanged,
+                    #mv_patch_t::Update(::core::option::Option::Some(value)) => #updated,
+                    #mv_patch_t::Update(::core::option::Option::None) => true,
+                }
+            },
+            "replace" => quote! {
+                match &self.#member {
+                    ::core::option::Option::None => #unchanged,
+                    ::core::option::Option::Some(value) => #updated,
+                }
+            },
+            "tri" => quote! {
+                match &self.#member {
+                    #crate_path::PatchOpt::Ignore => #unchanged,
+                    #crate_path::PatchOpt::SetNull => true,
+                    #crate_path::PatchOpt::SetValue(value) => #updated,
+                }
+            },
+            _ => unchanged,
+        });
+
+        // Each present field also gets a check whether its value is a no-op, where nested
+        // patches are probed for `NoopPatch`
+        let probe = quote! { (&#crate_path::__private::NoopProbe(value)).is_noop() };
+        let patch_field = match patch_p {
+            "required" => Some((
+                if with_serde && with_patch_null_is_error {
+                    let deserialize_with =
+                        format!("{}::__private::deserialize_non_null", quote!(#crate_path));
+                    vec![quote! { #[serde(default, deserialize_with = #deserialize_with)] }]
+                } else if with_serde && !with_transparent {
+                    vec![quote! { #[serde(default)] }]
+                } else {
+                    vec![]
+                },
+                quote! { #mv_patch_t<#patch_ty> },
+                quote! {
+                    match value {
+                        #mv_patch_t::Ignore => true,
+                        #mv_patch_t::Update(value) => #probe,
+                    }
+                },
+            )),
+            "optional" => Some((
+                if with_serde && !with_transparent {
+                    vec![quote! { #[serde(default)] }]
+                } else {
+                    vec![]
+                },
+                quote! { #mv_patch_t<::core::option::Option<#patch_ty>> },
+                quote! {
+                    match value {
+                        #mv_patch_t::Ignore => true,
+                        #mv_patch_t::Update(::core::option::Option::Some(value)) => #probe,
+                        #mv_patch_t::Update(::core::option::Option::None) => false,
+                    }
+                },
+            )),
+            "replace" => Some((
+                if with_serde {
+                    vec![quote! { #[serde(default)] }]
+                } else {
+                    vec![]
+                },
+                quote! { ::core::option::Option<#patch_ty> },
+                quote! {
+                    match value {
+                        ::core::option::Option::None => true,
+                        ::core::option::Option::Some(value) => #probe,
+                    }
+                },
+            )),
+            "tri" => Some((
+                if with_serde {
+                    vec![quote! { #[serde(default)] }]
+                } else {
+                    vec![]
+                },
+                quote! { #crate_path::PatchOpt<#patch_ty> },
+                quote! {
+                    match value {
+                        #crate_path::PatchOpt::Ignore => true,
+                        #crate_path::PatchOpt::SetNull => false,
+                        #crate_path::PatchOpt::SetValue(value) => #probe,
+                    }
+                },
+            )),
+            "forbidden" => None,
+            other => panic!("unknown patch policy: {other}"),
+        };
+        if let Some((mut attrs, ty, noop)) = patch_field {
+            has_patch = true;
+            let noop_fn = format_ident!("__views_noop_{}", ident_name);
+            if with_serde && !with_transparent {
+                let skip_fn = f.patch_skip_if.as_ref().map_or_else(
+                    || format!("Self::{noop_fn}"),
+                    |skip_if| {
+                        syn::parse_str::<syn::Path>(skip_if).unwrap_or_else(|_| {
+                            panic!("valid path in #[views(patch_skip_if = \"{skip_if}\")]")
+                        });
+                        skip_if.clone()
+                    },
+                );
+                attrs.push(quote! { #[serde(skip_serializing_if = #skip_fn)] });
+            }
+            patch_noops.push(quote! {
+                #[doc(hidden)]
+                fn #noop_fn(value: &#ty) -> bool {
+                    #[allow(unused_imports)]
+                    use #crate_path::__private::{NoopFallback as _, NoopViaPatch as _};
+                    #noop
+                }
+            });
+            patch_noop_fns.push(noop_fn);
+            let is_set = if patch_p == "replace" {
+                quote! { self.#member.is_some() }
+            } else {
+                quote! { !self.#member.is_ignore() }
+            };
+            let variant = match &view_ident {
+                Some(_) => format_ident!("{}", pascal_case(&ident_name)),
+                None => format_ident!("Field{index}"),
+            };
+            // Distinct names such as `foo` and `_foo` share a variant, which would otherwise
+            // be reported as a duplicate deep inside the generated enum
+            if with_patch_columns
+                && let Some((_, other, _)) = patch_columns
+                    .iter()
+                    .find(|(existing, ..)| *existing == variant)
+            {
+                return syn::Error::new_spanned(
+                    &f.ident,
+                    format!(
+                        "#[views(patch_columns)] maps both `{other}` and `{ident_name}` \
+                         to the `{base_name}PatchColumn::{variant}` variant"
+                    ),
+                )
+                .to_compile_error()
+                .into();
+            }
+            patch_columns.push((variant, ident_name.clone(), is_set));
+            // A merge patch omits ignored fields and writes everything else, `null` included
+            if with_merge_patch_serialize {
+                assert!(
+                    view_ident.is_some(),
+                    "#[views(merge_patch_serialize)] requires named fields"
+                );
+                assert!(
+                    !f.attrs.iter().any(is_serde_flatten),
+                    "#[views(merge_patch_serialize)] does not support #[serde(flatten)] fields, \
+                     but `{ident_name}` is flattened"
+                );
+                let key = f
+                    .attrs
+                    .iter()
+                    .find_map(serde_rename)
+                    .unwrap_or_else(|| rename_field(&ident_name, patch_rename_all));
+                merge_patch_entries.push(match patch_p {
+                    "replace" => quote! {
+                        if let ::core::option::Option::Some(value) = &self.#member {
+                            __map.serialize_entry(#key, value)?;
+                        }
+                    },
+                    "tri" => quote! {
+                        if !self.#member.is_ignore() {
+                            __map.serialize_entry(#key, &self.#member)?;
+                        }
+                    },
+                    _ => quote! {
+                        if let #mv_patch_t::Update(value) = &self.#member {
+                            __map.serialize_entry(#key, value)?;
+                        }
+                    },
+                });
+                if !meta.generics.params.is_empty() {
+                    merge_patch_bounds.push(syn::parse_quote! { #patch_ty: ::serde::Serialize });
+                }
+            }
+            // Opaque types need no `PatchMerge` impl, later updates simply replace them
+            patch_merges.push(if opaque {
+                let is_update = if patch_p == "replace" {
+                    quote! { other.#member.is_some() }
+                } else {
+                    quote! { !other.#member.is_ignore() }
+                };
+                quote! {
+                    if #is_update {
+                        self.#member = other.#member;
+                    }
+                }
+            } else if patch_p == "replace" {
+                // A `None` leaves the field untouched, so it must not replace an earlier value
+                quote! {
+                    if other.#member.is_some() {
+                        #crate_path::PatchMerge::merge_into(&mut self.#member, other.#member);
+                    }
+                }
+            } else {
+                quote! { #crate_path::PatchMerge::merge_into(&mut self.#member, other.#member); }
+            });
+            // Nested patches recurse into the field, opaque and overridden types are replaced
+            let apply = if f.patch_type.is_some() {
+                quote! { self.#model_member = ::core::convert::Into::into(value); }
+            } else if opaque {
+                quote! { self.#model_member = value; }
+            } else {
+                quote! { #crate_path::ApplyPatch::apply_patch(&mut self.#model_member, value); }
+            };
+            let reset = quote! { self.#model_member = ::core::default::Default::default(); };
+            patch_applies.push(match patch_p {
+                "required" => quote! {
+                    if let #mv_patch_t::Update(value) = patch.#member {
+                        #apply
+                    }
+                },
+                "optional" => quote! {
+                    match patch.#member {
+                        #mv_patch_t::Ignore => {}
+                        #mv_patch_t::Update(::core::option::Option::Some(value)) => { #apply }
+                        #mv_patch_t::Update(::core::option::Option::None) => { #reset }
+                    }
+                },
+                "replace" => quote! {
+                    if let ::core::option::Option::Some(value) = patch.#member {
+                        #apply
+                    }
+                },
+                _ => quote! {
+                    match patch.#member {
+                        #crate_path::PatchOpt::Ignore => {}
+                        #crate_path::PatchOpt::SetNull => { #reset }
+                        #crate_path::PatchOpt::SetValue(value) => { #apply }
+                    }
+                },
+            });
+            patch_fields.push(de_field(attrs, ty));
+        }
+    }
+
+    // Pre-existing predicates of the model are kept, followed by the `View` bounds
+    let mut generics = meta.generics.clone();
+    if !view_bounds.is_empty() {
+        generics.make_where_clause().predicates.extend(view_bounds);
     }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     // pull locals for quote!
-    let vis = &meta.vis;
+    let vis = if meta.public.unwrap_or(false) {
+        &syn::parse_quote!(pub)
+    } else {
+        &meta.vis
+    };
     let struct_attrs: Vec<_> = input
         .attrs
         .iter()
-        .filter(|attr| !attr.path().is_ident("views"))
+        .filter(|attr| !attr.path().is_ident("views") && !attr.path().is_ident("view"))
+        .filter(|attr| with_forward_repr || !attr.path().is_ident("repr"))
         .collect();
     let create_ident = &create_ident;
     let read_ident = &read_ident;
     let patch_ident = &patch_ident;
 
-    let create_fields_ts = &create_fields;
-    let read_fields_ts = &read_fields;
-    let patch_fields_ts = &patch_fields;
+    // Accessors are generated before the bodies, since they make the fields private
+    let create_accessors = if meta.create_accessors.unwrap_or(false) {
+        accessors(&mut create_fields)
+    } else {
+        Vec::new()
+    };
+    let read_accessors = if meta.get_accessors.unwrap_or(false) {
+        accessors(&mut read_fields)
+    } else {
+        Vec::new()
+    };
+    let patch_accessors = if meta.patch_accessors.unwrap_or(false) {
+        accessors(&mut patch_fields)
+    } else {
+        Vec::new()
+    };
+
+    let create_body = struct_body(style, where_clause, &create_fields);
+    let read_body = struct_body(style, where_clause, &read_fields);
+    let patch_body = struct_body(style, where_clause, &patch_fields);
 
     // Build items conditionally
     let mut items = Vec::<proc_macro2::TokenStream>::new();
 
-    let serialize_attrs = if with_serde {
-        quote! {
-            #[derive(::serde::Serialize)]
-            #[serde(deny_unknown_fields)]
-        }
+    // Newtype views delegate straight to their single field
+    let serde_container = if with_transparent {
+        Some(quote!(transparent))
+    } else if with_flatten {
+        None
     } else {
-        quote! {}
+        Some(quote!(deny_unknown_fields))
     };
 
-    let deserialize_attrs = if with_serde {
+    // Per-mode casing falls back to the container-wide `rename_all`, and a view that allows
+    // unknown fields drops `deny_unknown_fields`
+    let serde_attrs = |derive: proc_macro2::TokenStream,
+                       rename_all: Option<&String>,
+                       bound: Option<&String>,
+                       allow_unknown: Option<bool>| {
+        if !with_serde {
+            return quote! {};
+        }
+        let rename_all = rename_all
+            .or(meta.rename_all.as_ref())
+            .map(|rename_all| quote! { #[serde(rename_all = #rename_all)] });
+        let bound = bound
+            .or(meta.serde_bound.as_ref())
+            .map(|bound| quote! { #[serde(bound = #bound)] });
+        let container = serde_container
+            .as_ref()
+            .filter(|_| with_transparent || !allow_unknown.unwrap_or(false))
+            .map(|container| quote! { #[serde(#container)] });
         quote! {
-            #[derive(::serde::Deserialize)]
-            #[serde(deny_unknown_fields)]
+            #[derive(#derive)]
+            #container
+            #rename_all
+            #bound
         }
-    } else {
-        quote! {}
     };
+    // Bounding a non-generic Create view on its plain field types leaves a missing
+    // `Deserialize` impl to the field assertions below, instead of erroring inside the
+    // serde derive
+    let create_deserialize_bound =
+        (meta.generics.params.is_empty() && !create_deserialize_tys.is_empty()).then(|| {
+            create_deserialize_tys
+                .iter()
+                .map(|ty| format!("{ty}: ::serde::Deserialize<'de>"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+    let create_serde_attrs = serde_attrs(
+        quote!(::serde::Deserialize),
+        meta.create_rename_all.as_ref(),
+        meta.create_serde_bound
+            .as_ref()
+            .or(meta.serde_bound.as_ref())
+            .or(create_deserialize_bound.as_ref()),
+        meta.create_allow_unknown_fields,
+    );
+    // Runtime dispatch deserializes Get views as well
+    let read_serde_attrs = serde_attrs(
+        if with_runtime_dispatch {
+            quote!(::serde::Serialize, ::serde::Deserialize)
+        } else {
+            quote!(::serde::Serialize)
+        },
+        meta.get_rename_all.as_ref(),
+        meta.get_serde_bound.as_ref(),
+        meta.get_allow_unknown_fields,
+    );
+    // A merge patch document is serialized by hand
+    let patch_serde_attrs = serde_attrs(
+        if with_merge_patch_serialize {
+            quote!(::serde::Deserialize)
+        } else {
+            quote!(::serde::Serialize, ::serde::Deserialize)
+        },
+        meta.patch_rename_all.as_ref(),
+        meta.patch_serde_bound.as_ref(),
+        meta.patch_allow_unknown_fields,
+    );
+    // Views with redacted fields implement `Debug` themselves
+    let extra_derives = |paths: &PathList, fields: &[ViewField]| {
+        let redacted = fields.iter().any(|field| field.redacted);
+        let paths: Vec<_> = paths
+            .iter()
+            .filter(|path| !redacted || path.segments.last().is_none_or(|s| s.ident != "Debug"))
+            .collect();
+        if paths.is_empty() {
+            return quote! {};
+        }
+        quote! { #[derive(#(#paths),*)] }
+    };
+    let create_derives = extra_derives(&meta.create_derive, &create_fields);
+    let read_derives = extra_derives(&meta.get_derive, &read_fields);
+    let patch_derives = extra_derives(&meta.patch_derive, &patch_fields);
+
+    // Mutually exclusive fields are checked after deserializing into an unchecked twin
+    let create_xor_checks: Vec<_> = meta
+        .create_xor
+        .iter()
+        .map(|group| {
+            assert!(with_serde, "#[views(create_xor)] requires #[views(serde)]");
+            assert!(
+                group.len() >= 2,
+                "#[views(create_xor)] requires at least two fields"
+            );
+            let members = group.iter().map(|name| {
+                let name = name.value();
+                let index = create_optional_names
+                    .iter()
+                    .position(|optional| *optional == name)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "#[views(create_xor)] requires `{name}` to be a \
+                             create = \"optional\" field"
+                        )
+                    });
+                &create_optional_members[index]
+            });
+            quote! {
+                #crate_path::ExclusiveFieldsError::check(
+                    &[#(#group),*],
+                    &[#(__view.#members.is_some()),*],
+                )?;
+            }
+        })
+        .collect();
 
     if has_create {
+        let create_default = if with_create_default {
+            quote! { #[derive(::core::default::Default)] }
+        } else {
+            quote! {}
+        };
+        let create_try_from = if create_xor_checks.is_empty() {
+            quote! {}
+        } else {
+            let unchecked_ident = format_ident!("__{base_name}CreateUnchecked");
+            let unchecked_ty = quote! { #unchecked_ident #ty_generics }.to_string();
+            let members: Vec<_> = create_fields.iter().map(|field| &field.member).collect();
+            items.push(quote! {
+                #[doc(hidden)]
+                #create_serde_attrs
+                #vis struct #unchecked_ident #decl_generics #create_body
+
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::TryFrom<#unchecked_ident #ty_generics>
+                    for #create_ident #ty_generics #where_clause
+                {
+                    type Error = #crate_path::ExclusiveFieldsError;
+
+                    fn try_from(
+                        __view: #unchecked_ident #ty_generics,
+                    ) -> ::core::result::Result<Self, Self::Error> {
+                        #(#create_xor_checks)*
+                        ::core::result::Result::Ok(Self { #(#members: __view.#members,)* })
+                    }
+                }
+            });
+            quote! { #[serde(try_from = #unchecked_ty)] }
+        };
         items.push(quote! {
-            #deserialize_attrs
+            #create_default
+            #create_derives
+            #create_serde_attrs
+            #create_try_from
             #(#struct_attrs)*
-            #vis struct #create_ident #ty_generics
-            #where_clause
-            {
-                #(#create_fields_ts)*
-            }
+            #vis struct #create_ident #decl_generics #create_body
 
+            #[automatically_derived]
             impl #impl_generics #mv_view<#mv_create> for #name #ty_generics #where_clause {
                 type Type = #create_ident #ty_generics;
             }
         });
+
+        items.extend(redacted_debug(
+            create_ident,
+            &generics,
+            style,
+            &create_fields,
+        ));
+
+        let create_field_count = create_fields.len();
+        items.push(quote! {
+            impl #impl_generics #create_ident #ty_generics #where_clause {
+                /// Number of fields in this view
+                pub const FIELD_COUNT: usize = #create_field_count;
+
+                #(#create_accessors)*
+            }
+        });
+
+        // Report Create fields that cannot be deserialized at the field instead of deep
+        // inside the serde derive; generic models get their bounds inferred by serde instead,
+        // and their const parameters are not in scope here
+        if with_serde && meta.generics.params.is_empty() {
+            items.push(quote! {
+                const _: () = {
+                    fn __views_assert_deserialize<'de, T: ::serde::Deserialize<'de>>() {}
+
+                    #[allow(dead_code)]
+                    fn __views_assert_create_fields() {
+                        #(#create_deserialize_checks)*
+                    }
+                };
+            });
+        }
+
+        if let Some((validate_fn, validate_err)) = &create_validate {
+            items.push(quote! {
+                impl #impl_generics #create_ident #ty_generics #where_clause {
+                    /// Validates this view using the configured validation function.
+                    ///
+                    /// # Errors
+                    ///
+                    /// Returns the error produced by the validation function.
+                    pub fn validate(&self) -> ::core::result::Result<(), #validate_err> {
+                        #validate_fn(self)
+                    }
+                }
+            });
+        }
+
+        if with_create_new {
+            items.push(quote! {
+                impl #impl_generics #create_ident #ty_generics #where_clause {
+                    /// Creates the view from its required fields, leaving optional fields `None`.
+                    pub fn new(#(#create_new_args),*) -> Self {
+                        Self {
+                            #(#create_new_inits,)*
+                        }
+                    }
+                }
+            });
+        }
+
+        if with_create_from {
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::From<#name #ty_generics>
+                    for #create_ident #ty_generics #where_clause
+                {
+                    fn from(__model: #name #ty_generics) -> Self {
+                        Self { #(#create_from_inits,)* }
+                    }
+                }
+            });
+        }
+
+        if with_create_introspect {
+            items.push(quote! {
+                impl #impl_generics #create_ident #ty_generics #where_clause {
+                    /// Returns the names of all `create = "optional"` fields that are `None`.
+                    pub fn missing_optionals(&self) -> ::std::vec::Vec<&'static str> {
+                        #[allow(unused_mut)]
+                        let mut missing = ::std::vec::Vec::new();
+                        #(
+                            if self.#create_optional_members.is_none() {
+                                missing.push(#create_optional_names);
+                            }
+                        )*
+                        missing
+                    }
+                }
+            });
+        }
+    } else if create_validate.is_some() {
+        panic!("#[views(create_validate)] requires at least one field in the Create view");
+    } else if with_create_from {
+        panic!("#[views(create_from)] requires at least one field in the Create view");
     }
 
     if has_get {
         items.push(quote! {
-            #serialize_attrs
+            #read_derives
+            #read_serde_attrs
             #(#struct_attrs)*
-            #vis struct #read_ident #ty_generics
-            #where_clause
-            {
-                #(#read_fields_ts)*
-            }
+            #vis struct #read_ident #decl_generics #read_body
 
+            #[automatically_derived]
             impl #impl_generics #mv_view<#mv_get> for #name #ty_generics #where_clause {
                 type Type = #read_ident #ty_generics;
             }
         });
+
+        items.extend(redacted_debug(read_ident, &generics, style, &read_fields));
+
+        let read_field_count = read_fields.len();
+        items.push(quote! {
+            impl #impl_generics #read_ident #ty_generics #where_clause {
+                /// Number of fields in this view
+                pub const FIELD_COUNT: usize = #read_field_count;
+
+                #(#read_accessors)*
+            }
+        });
+
+        if with_get_from {
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::From<#name #ty_generics>
+                    for #read_ident #ty_generics #where_clause
+                {
+                    fn from(__model: #name #ty_generics) -> Self {
+                        Self { #(#get_from_inits,)* }
+                    }
+                }
+            });
+        }
+
+        if with_get_eq_model {
+            items.push(quote! {
+                impl #impl_generics #read_ident #ty_generics #where_clause {
+                    /// Returns `true` if every field of this view equals the model's field;
+                    /// `get = "optional"` fields that are `None` are not compared.
+                    pub fn matches_model(&self, __model: &#name #ty_generics) -> bool {
+                        #(#get_eq_checks)&&*
+                    }
+                }
+            });
+        }
+
+        if with_get_introspect {
+            items.push(quote! {
+                impl #impl_generics #read_ident #ty_generics #where_clause {
+                    /// Returns the names of all `get = "optional"` fields that are `Some`.
+                    pub fn present_optionals(&self) -> ::std::vec::Vec<&'static str> {
+                        #[allow(unused_mut)]
+                        let mut present = ::std::vec::Vec::new();
+                        #(
+                            if self.#get_optional_members.is_some() {
+                                present.push(#get_optional_names);
+                            }
+                        )*
+                        present
+                    }
+                }
+            });
+        }
+
+        if with_serde && cfg!(feature = "json") {
+            let json = quote!(#crate_path::__private::serde_json);
+            let mut generics = generics.clone();
+            if !generics.params.is_empty() {
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote! { #read_ident #ty_generics: ::serde::Serialize });
+            }
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::From<#read_ident #ty_generics>
+                    for #json::Value #where_clause
+                {
+                    fn from(view: #read_ident #ty_generics) -> Self {
+                        #json::to_value(view).expect("Get view serializes to JSON")
+                    }
+                }
+            });
+        }
+
+        if with_get_ref {
+            let ref_ident = format_ident!("{base_name}GetRef");
+            let mut ref_generics = generics.clone();
+            ref_generics.params.insert(0, syn::parse_quote!('a));
+            let (ref_impl_generics, ref_ty_generics, _) = ref_generics.split_for_impl();
+            let ref_decl_generics = struct_generics(&ref_generics);
+            let ref_body = struct_body(style, where_clause, &read_ref_fields);
+            items.extend(redacted_debug(
+                &ref_ident,
+                &ref_generics,
+                style,
+                &read_ref_fields,
+            ));
+            let ref_members = read_ref_fields.iter().map(|field| &field.member);
+            let ref_docs = input
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"));
+            let ref_serde_attrs = if with_serde {
+                let transparent = with_transparent.then(|| quote! { #[serde(transparent)] });
+                let rename_all = meta
+                    .get_rename_all
+                    .as_ref()
+                    .or(meta.rename_all.as_ref())
+                    .map(|rename_all| quote! { #[serde(rename_all = #rename_all)] });
+                let bound = meta
+                    .get_serde_bound
+                    .as_ref()
+                    .or(meta.serde_bound.as_ref())
+                    .map(|bound| quote! { #[serde(bound = #bound)] });
+                quote! {
+                    #[derive(::serde::Serialize)]
+                    #transparent
+                    #rename_all
+                    #bound
+                }
+            } else {
+                quote! {}
+            };
+            items.push(quote! {
+                #[derive(::core::clone::Clone, ::core::marker::Copy)]
+                #ref_serde_attrs
+                #(#ref_docs)*
+                #vis struct #ref_ident #ref_decl_generics #ref_body
+
+                impl #impl_generics #read_ident #ty_generics #where_clause {
+                    /// Borrows this view without cloning its fields.
+                    pub fn as_ref_view<'a>(&'a self) -> #ref_ident #ref_ty_generics {
+                        #ref_ident {
+                            #(#ref_members: #read_ref_exprs,)*
+                        }
+                    }
+                }
+
+                #[automatically_derived]
+                impl #ref_impl_generics ::core::convert::From<&'a #read_ident #ty_generics>
+                    for #ref_ident #ref_ty_generics #where_clause
+                {
+                    fn from(view: &'a #read_ident #ty_generics) -> Self {
+                        view.as_ref_view()
+                    }
+                }
+            });
+        }
+
+        if with_get_try_into {
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::TryFrom<#read_ident #ty_generics>
+                    for #name #ty_generics #where_clause
+                {
+                    type Error = #crate_path::MissingFieldsError;
+
+                    fn try_from(
+                        __view: #read_ident #ty_generics,
+                    ) -> ::core::result::Result<Self, Self::Error> {
+                        let mut __missing = #crate_path::MissingFieldsError::default();
+                        #(#get_try_bindings)*
+                        match (#(#get_try_idents,)*) {
+                            (#(::core::option::Option::Some(#get_try_idents),)*) => {
+                                ::core::result::Result::Ok(Self {
+                                    #(#get_try_members: #get_try_idents,)*
+                                })
+                            }
+                            _ => ::core::result::Result::Err(__missing),
+                        }
+                    }
+                }
+            });
+        }
+    } else if with_get_from {
+        panic!("#[views(get_from)] requires at least one field in the Get view");
+    } else if with_get_eq_model {
+        panic!("#[views(get_eq_model)] requires at least one field in the Get view");
     }
 
     if has_patch {
         items.push(quote! {
             #[derive(::core::default::Default)]
-            #deserialize_attrs
+            #patch_derives
+            #patch_serde_attrs
             #(#struct_attrs)*
-            #vis struct #patch_ident #ty_generics
-            #where_clause
-            {
-                #(#patch_fields_ts)*
-            }
+            #vis struct #patch_ident #decl_generics #patch_body
 
+            #[automatically_derived]
             impl #impl_generics #mv_view<#mv_patch> for #name #ty_generics #where_clause {
                 type Type = #patch_ident #ty_generics;
             }
         });
+
+        items.extend(redacted_debug(patch_ident, &generics, style, &patch_fields));
+
+        let patch_field_count = patch_fields.len();
+        items.push(quote! {
+            impl #impl_generics #patch_ident #ty_generics #where_clause {
+                /// Number of fields in this view
+                pub const FIELD_COUNT: usize = #patch_field_count;
+
+                #(#patch_accessors)*
+            }
+        });
+
+        let patch_members = patch_fields.iter().map(|field| &field.member);
+        items.push(quote! {
+            impl #impl_generics #patch_ident #ty_generics #where_clause {
+                #(#patch_noops)*
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #crate_path::NoopPatch for #patch_ident #ty_generics #where_clause {
+                fn is_noop(&self) -> bool {
+                    true #(&& Self::#patch_noop_fns(&self.#patch_members))*
+                }
+            }
+        });
+
+        if with_merge {
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics #crate_path::PatchMerge for #patch_ident #ty_generics #where_clause {
+                    fn merge_into(&mut self, other: Self) {
+                        #(#patch_merges)*
+                    }
+                }
+            });
+        }
+
+        if with_patch_apply {
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics #crate_path::ApplyPatch for #name #ty_generics #where_clause {
+                    fn apply_patch(&mut self, patch: #patch_ident #ty_generics) {
+                        #(#patch_applies)*
+                    }
+                }
+            });
+        }
+
+        if let Some((validate_fn, validate_err)) = &patch_validate {
+            items.push(quote! {
+                impl #impl_generics #patch_ident #ty_generics #where_clause {
+                    /// Validates this view using the configured validation function.
+                    ///
+                    /// # Errors
+                    ///
+                    /// Returns the error produced by the validation function.
+                    pub fn validate(&self) -> ::core::result::Result<(), #validate_err> {
+                        #validate_fn(self)
+                    }
+                }
+            });
+        }
+
+        if with_patch_assert {
+            items.push(quote! {
+                impl #impl_generics #patch_ident #ty_generics #where_clause {
+                    /// Checks whether applying this patch to `before` yields `after`.
+                    ///
+                    /// Updated fields must equal their value in `after`, and all other fields
+                    /// must be unchanged between `before` and `after`.
+                    pub fn applies_to(
+                        &self,
+                        before: &#name #ty_generics,
+                        after: &#name #ty_generics,
+                    ) -> bool {
+                        true #(&& #patch_checks)*
+                    }
+                }
+            });
+        }
+
+        if with_merge_patch_serialize {
+            let mut generics = generics.clone();
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(merge_patch_bounds);
+            let (impl_generics, _, where_clause) = generics.split_for_impl();
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::serde::Serialize for #patch_ident #ty_generics #where_clause {
+                    fn serialize<__S>(
+                        &self,
+                        __serializer: __S,
+                    ) -> ::core::result::Result<__S::Ok, __S::Error>
+                    where
+                        __S: ::serde::Serializer,
+                    {
+                        use ::serde::ser::SerializeMap as _;
+                        let mut __map = __serializer.serialize_map(::core::option::Option::None)?;
+                        #(#merge_patch_entries)*
+                        __map.end()
+                    }
+                }
+            });
+        }
+
+        if with_patch_columns {
+            let column_ident = format_ident!("{base_name}PatchColumn");
+            let variants: Vec<_> = patch_columns.iter().map(|(variant, ..)| variant).collect();
+            let names = patch_columns.iter().map(|(_, name, _)| name);
+            let checks = patch_columns.iter().map(|(.., is_set)| is_set);
+            let variant_docs = patch_columns
+                .iter()
+                .map(|(_, name, _)| format!("The `{name}` field."));
+            let doc = format!("The patchable columns of [`{patch_ident}`].");
+            items.push(quote! {
+                #[doc = #doc]
+                #[derive(
+                    ::core::fmt::Debug,
+                    ::core::clone::Clone,
+                    ::core::marker::Copy,
+                    ::core::cmp::PartialEq,
+                    ::core::cmp::Eq,
+                    ::core::hash::Hash,
+                )]
+                #vis enum #column_ident {
+                    #(
+                        #[doc = #variant_docs]
+                        #variants,
+                    )*
+                }
+
+                impl #column_ident {
+                    /// Returns the Rust name of the column's field.
+                    pub const fn name(self) -> &'static str {
+                        match self {
+                            #(Self::#variants => #names,)*
+                        }
+                    }
+                }
+
+                impl #impl_generics #patch_ident #ty_generics #where_clause {
+                    /// Returns the columns this patch updates, in field order.
+                    pub fn set_columns(&self) -> ::std::vec::Vec<#column_ident> {
+                        let mut columns = ::std::vec::Vec::new();
+                        #(
+                            if #checks {
+                                columns.push(#column_ident::#variants);
+                            }
+                        )*
+                        columns
+                    }
+                }
+            });
+        }
+
+        if with_patch_non_empty {
+            items.push(quote! {
+                impl #impl_generics #patch_ident #ty_generics #where_clause {
+                    /// Returns this patch if it changes anything.
+                    ///
+                    /// # Errors
+                    ///
+                    /// Returns `EmptyPatch` if applying this patch would not change anything.
+                    pub fn require_change(
+                        self,
+                    ) -> ::core::result::Result<Self, #crate_path::EmptyPatch> {
+                        if #crate_path::NoopPatch::is_noop(&self) {
+                            ::core::result::Result::Err(#crate_path::EmptyPatch)
+                        } else {
+                            ::core::result::Result::Ok(self)
+                        }
+                    }
+                }
+            });
+        }
+
+        if with_patch_to_create && has_create {
+            items.push(quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::TryFrom<#patch_ident #ty_generics>
+                    for #create_ident #ty_generics #where_clause
+                {
+                    type Error = #crate_path::MissingFieldsError;
+
+                    fn try_from(
+                        __patch: #patch_ident #ty_generics,
+                    ) -> ::core::result::Result<Self, Self::Error> {
+                        let mut __missing = #crate_path::MissingFieldsError::default();
+                        #(#patch_to_create_bindings)*
+                        match (#(#patch_to_create_idents,)*) {
+                            (#(::core::option::Option::Some(#patch_to_create_idents),)*) => {
+                                ::core::result::Result::Ok(Self {
+                                    #(#patch_to_create_members: #patch_to_create_idents,)*
+                                })
+                            }
+                            _ => ::core::result::Result::Err(__missing),
+                        }
+                    }
+                }
+            });
+        } else if with_patch_to_create {
+            panic!("#[views(patch_to_create)] requires at least one field in the Create view");
+        }
+
+        if let Some(normalize_fn) = &patch_normalize {
+            items.push(quote! {
+                impl #impl_generics #patch_ident #ty_generics #where_clause {
+                    /// Normalizes this view using the configured normalization function.
+                    #[must_use]
+                    pub fn normalized(mut self) -> Self {
+                        #normalize_fn(&mut self);
+                        self
+                    }
+                }
+            });
+        }
+    } else if patch_validate.is_some() {
+        panic!("#[views(patch_validate)] requires at least one field in the Patch view");
+    } else if patch_normalize.is_some() {
+        panic!("#[views(patch_normalize)] requires at least one field in the Patch view");
+    } else if with_patch_assert {
+        panic!("#[views(patch_assert)] requires at least one field in the Patch view");
+    } else if with_patch_apply {
+        panic!("#[views(patch_apply)] requires at least one field in the Patch view");
+    } else if with_patch_non_empty {
+        panic!("#[views(patch_non_empty)] requires at least one field in the Patch view");
+    } else if with_patch_columns {
+        panic!("#[views(patch_columns)] requires at least one field in the Patch view");
+    } else if with_patch_to_create {
+        panic!("#[views(patch_to_create)] requires at least one field in the Patch view");
+    }
+
+    if with_into_view {
+        for (present, mode, view_ident, inits) in [
+            (has_get, &mv_get, read_ident, &into_get_inits),
+            (has_create, &mv_create, create_ident, &into_create_inits),
+            (has_patch, &mv_patch, patch_ident, &into_patch_inits),
+        ] {
+            if present {
+                items.push(quote! {
+                    #[automatically_derived]
+                    impl #impl_generics #crate_path::IntoView<#mode> for #name #ty_generics #where_clause {
+                        fn into_view(self) -> #view_ident #ty_generics {
+                            let __model = self;
+                            #view_ident { #(#inits,)* }
+                        }
+                    }
+                });
+            }
+        }
+        items.push(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Converts the model into its view in mode `M`.
+                pub fn to_view<M: #crate_path::ViewMode>(self) -> <Self as #mv_view<M>>::Type
+                where
+                    Self: #crate_path::IntoView<M>,
+                {
+                    #crate_path::IntoView::<M>::into_view(self)
+                }
+            }
+        });
+    }
+
+    if with_runtime_dispatch {
+        assert!(
+            has_get && has_create && has_patch,
+            "#[views(runtime_dispatch)] requires at least one field in every view"
+        );
+        let any_ident = format_ident!("{base_name}AnyView");
+        let any_doc = format!("Any view of [`{name}`], selected at runtime.");
+        let kind = quote!(#crate_path::ViewModeKind);
+        let json = cfg!(feature = "json").then(|| {
+            let json = quote!(#crate_path::__private::serde_json);
+            quote! {
+                /// Deserializes the view selected by `mode` from a JSON string.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error if `json` is not a valid view for `mode`.
+                pub fn deserialize_view_json(
+                    mode: #kind,
+                    json: &str,
+                ) -> ::core::result::Result<Self, #json::Error> {
+                    let mut deserializer = #json::Deserializer::from_str(json);
+                    let view = Self::deserialize_view(mode, &mut deserializer)?;
+                    deserializer.end()?;
+                    ::core::result::Result::Ok(view)
+                }
+            }
+        });
+        items.push(quote! {
+            #[doc = #any_doc]
+            #vis enum #any_ident #decl_generics #where_clause {
+                /// A Get view
+                Get(#read_ident #ty_generics),
+                /// A Create view
+                Create(#create_ident #ty_generics),
+                /// A Patch view
+                Patch(#patch_ident #ty_generics),
+            }
+
+            impl #impl_generics #any_ident #ty_generics #where_clause {
+                /// Deserializes the view selected by `mode`.
+                ///
+                /// # Errors
+                ///
+                /// Returns the deserializer's error if the input is not a valid view for
+                /// `mode`.
+                pub fn deserialize_view<'de, D>(
+                    mode: #kind,
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                    #read_ident #ty_generics: ::serde::Deserialize<'de>,
+                    #create_ident #ty_generics: ::serde::Deserialize<'de>,
+                    #patch_ident #ty_generics: ::serde::Deserialize<'de>,
+                {
+                    ::core::result::Result::Ok(match mode {
+                        #kind::Get => Self::Get(::serde::Deserialize::deserialize(deserializer)?),
+                        #kind::Create => {
+                            Self::Create(::serde::Deserialize::deserialize(deserializer)?)
+                        }
+                        #kind::Patch => {
+                            Self::Patch(::serde::Deserialize::deserialize(deserializer)?)
+                        }
+                    })
+                }
+
+                #json
+
+                /// Returns the mode of the contained view.
+                pub const fn mode(&self) -> #kind {
+                    match self {
+                        Self::Get(_) => #kind::Get,
+                        Self::Create(_) => #kind::Create,
+                        Self::Patch(_) => #kind::Patch,
+                    }
+                }
+            }
+        });
     }
 
     let out = quote! { #(#items)* };
     out.into()
 }
+
+/// Parses a `{mode}_validate` / `{mode}_validate_error` attribute pair into the
+/// validation function path and its error type.
+fn validate_hook(
+    mode: &str,
+    validate_fn: Option<&str>,
+    validate_err: Option<&str>,
+) -> Option<(syn::Path, Type)> {
+    match (validate_fn, validate_err) {
+        (Some(validate_fn), Some(validate_err)) => Some((
+            syn::parse_str(validate_fn)
+                .unwrap_or_else(|_| panic!("valid path in #[views({mode}_validate = \"...\")]")),
+            syn::parse_str(validate_err).unwrap_or_else(|_| {
+                panic!("valid type in #[views({mode}_validate_error = \"...\")]")
+            }),
+        )),
+        (Some(_), None) => {
+            panic!("#[views({mode}_validate)] requires #[views({mode}_validate_error = \"...\")]")
+        }
+        (None, Some(_)) => {
+            panic!("#[views({mode}_validate_error)] requires #[views({mode}_validate = \"...\")]")
+        }
+        (None, None) => None,
+    }
+}
+
+/// Serde field attribute items that apply regardless of the field's type in a view.
+const FORWARDED: &[&str] = &[
+    "rename",
+    "alias",
+    "flatten",
+    "skip",
+    "skip_serializing",
+    "skip_deserializing",
+    "bound",
+];
+
+/// Serde field attribute items that keep a field from being deserialized.
+const SKIP_DESERIALIZING: &[&str] = &["skip", "skip_deserializing"];
+
+/// Splits a `#[serde(...)]` attribute into its comma-separated items.
+fn serde_items(attr: &syn::Attribute) -> Vec<Vec<proc_macro2::TokenTree>> {
+    let syn::Meta::List(list) = &attr.meta else {
+        return Vec::new();
+    };
+    let mut items = vec![Vec::new()];
+    for token in list.tokens.clone() {
+        match token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                items.push(Vec::new());
+            }
+            token => items.last_mut().expect("never empty").push(token),
+        }
+    }
+    items
+}
+
+/// Checks whether a serde attribute item starts with one of `names`.
+fn item_is(item: &[proc_macro2::TokenTree], names: &[&str]) -> bool {
+    matches!(item.first(), Some(proc_macro2::TokenTree::Ident(ident))
+        if names.iter().any(|name| ident == name))
+}
+
+/// Keeps the items of a field's `#[serde(...)]` attribute that apply regardless of the
+/// field's type in a view, or returns `None` if there are none.
+fn forwarded_serde_attr(attr: &syn::Attribute) -> Option<proc_macro2::TokenStream> {
+    let kept: Vec<proc_macro2::TokenStream> = serde_items(attr)
+        .into_iter()
+        .filter(|item| item_is(item, FORWARDED))
+        .map(|item| item.into_iter().collect())
+        .collect();
+    if kept.is_empty() {
+        None
+    } else {
+        Some(quote! { #[serde(#(#kept),*)] })
+    }
+}
+
+/// Checks whether an attribute is `#[serde(...)]` containing `flatten`.
+fn is_serde_flatten(attr: &syn::Attribute) -> bool {
+    let syn::Meta::List(list) = &attr.meta else {
+        return false;
+    };
+    list.path.is_ident("serde")
+        && list.tokens.clone().into_iter().any(
+            |token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "flatten"),
+        )
+}
+
+/// Returns `T` if the type is syntactically an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if path.qself.is_some() || segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Returns the name given by `rename = "..."` in a `#[serde(...)]` attribute.
+fn serde_rename(attr: &syn::Attribute) -> Option<String> {
+    let syn::Meta::List(list) = &attr.meta else {
+        return None;
+    };
+    if !list.path.is_ident("serde") {
+        return None;
+    }
+    let tokens: Vec<_> = list.tokens.clone().into_iter().collect();
+    tokens.windows(3).find_map(|window| match window {
+        [
+            proc_macro2::TokenTree::Ident(ident),
+            proc_macro2::TokenTree::Punct(punct),
+            proc_macro2::TokenTree::Literal(literal),
+        ] if ident == "rename" && punct.as_char() == '=' => {
+            syn::parse_str::<syn::LitStr>(&literal.to_string())
+                .ok()
+                .map(|name| name.value())
+        }
+        _ => None,
+    })
+}
+
+/// Applies a serde `rename_all` rule to a `snake_case` field name, as serde does.
+fn rename_field(name: &str, rule: Option<&str>) -> String {
+    match rule {
+        None | Some("lowercase" | "snake_case") => name.to_string(),
+        Some("UPPERCASE" | "SCREAMING_SNAKE_CASE") => name.to_ascii_uppercase(),
+        Some("PascalCase") => pascal_case(name),
+        Some("camelCase") => {
+            let pascal = pascal_case(name);
+            let mut chars = pascal.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_lowercase().chain(chars).collect()
+            })
+        }
+        Some("kebab-case") => name.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => name.to_ascii_uppercase().replace('_', "-"),
+        Some(other) => panic!("unknown rename_all rule: {other}"),
+    }
+}
+
+/// Converts a `snake_case` field name to `PascalCase`, e.g. for enum variants.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect()
+}
+
+/// Checks whether a type is a `Copy` primitive, which borrowed views keep by value.
+fn is_copy_primitive(ty: &Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "char", "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128",
+        "isize", "usize", "f32", "f64",
+    ];
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path
+            .path
+            .get_ident()
+            .is_some_and(|ident| PRIMITIVES.iter().any(|primitive| ident == primitive))
+}
+
+/// Returns `true` if `ty` is one of the type parameters `params`.
+fn is_type_param(ty: &Type, params: &[&syn::Ident]) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path
+            .path
+            .get_ident()
+            .is_some_and(|ident| params.contains(&ident))
+}
+
+/// Checks whether a type is a path without generic arguments, e.g. `String`.
+fn is_plain_path(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path
+            .path
+            .segments
+            .iter()
+            .all(|segment| segment.arguments.is_none())
+}
+
+/// Renders the generic parameters for declaring a view struct: like the model's type
+/// generics, so bounds stay in the where clause, but keeping the `const` of const
+/// parameters, which the type generics drop.
+fn struct_generics(generics: &syn::Generics) -> proc_macro2::TokenStream {
+    if generics.params.is_empty() {
+        return quote! {};
+    }
+    let params = generics.params.iter().map(|param| match param {
+        syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+        syn::GenericParam::Const(param) => {
+            let (ident, ty) = (&param.ident, &param.ty);
+            quote! { const #ident: #ty }
+        }
+    });
+    quote! { <#(#params),*> }
+}
+
+/// Emits a call asserting that `ty` implements `Deserialize`, spanned at the field type
+/// so that an unmet bound is reported there.
+fn deserialize_check(field_ty: &Type, ty: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    fn respan(
+        tokens: proc_macro2::TokenStream,
+        span: proc_macro2::Span,
+    ) -> proc_macro2::TokenStream {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                if let proc_macro2::TokenTree::Group(group) = &token {
+                    let mut respanned =
+                        proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                    respanned.set_span(span);
+                    token = respanned.into();
+                } else {
+                    token.set_span(span);
+                }
+                token
+            })
+            .collect()
+    }
+
+    let span = field_ty.span();
+    let ty = respan(ty.clone(), span);
+    quote_spanned! {span=> __views_assert_deserialize::<#ty>(); }
+}
+
+/// A field of a generated view type.
+struct ViewField {
+    /// Field attributes, e.g. forwarded docs and serde attributes
+    attrs: Vec<proc_macro2::TokenStream>,
+    /// Field name, or position for tuple structs
+    member: syn::Member,
+    ty: proc_macro2::TokenStream,
+    /// Whether the field is `pub`, rather than private behind an accessor
+    public: bool,
+    /// Whether the field's value is hidden in `Debug` output
+    redacted: bool,
+}
+
+impl ToTokens for ViewField {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let attrs = &self.attrs;
+        let ty = &self.ty;
+        let vis = self.public.then(|| quote!(pub));
+        tokens.extend(match &self.member {
+            syn::Member::Named(ident) => quote! { #(#attrs)* #vis #ident: #ty },
+            syn::Member::Unnamed(_) => quote! { #(#attrs)* #vis #ty },
+        });
+    }
+}
+
+/// Makes the fields private and returns a getter for each, named like the field
+/// (`field0`, `field1`, ... for tuple structs).
+fn accessors(fields: &mut [ViewField]) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter_mut()
+        .map(|field| {
+            field.public = false;
+            let member = &field.member;
+            let ty = &field.ty;
+            let name = match member {
+                syn::Member::Named(ident) => ident.clone(),
+                syn::Member::Unnamed(index) => format_ident!("field{}", index.index),
+            };
+            let doc = format!("Returns the `{}` field.", name.unraw());
+            quote! {
+                #[doc = #doc]
+                pub const fn #name(&self) -> &#ty {
+                    &self.#member
+                }
+            }
+        })
+        .collect()
+}
+
+/// Emits a `Debug` impl for a view that hides the values of its redacted fields, or nothing
+/// if the view has none.
+fn redacted_debug(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    style: darling::ast::Style,
+    fields: &[ViewField],
+) -> Option<proc_macro2::TokenStream> {
+    if !fields.iter().any(|field| field.redacted) {
+        return None;
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = ident.unraw().to_string();
+    let entries = fields.iter().map(|field| {
+        let member = &field.member;
+        let value = if field.redacted {
+            quote! { &::core::format_args!("***") }
+        } else {
+            quote! { &self.#member }
+        };
+        match member {
+            syn::Member::Named(ident) => {
+                let field_name = ident.unraw().to_string();
+                quote! { .field(#field_name, #value) }
+            }
+            syn::Member::Unnamed(_) => quote! { .field(#value) },
+        }
+    });
+    let builder = if style == darling::ast::Style::Tuple {
+        quote! { debug_tuple }
+    } else {
+        quote! { debug_struct }
+    };
+    Some(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.#builder(#name) #(#entries)* .finish()
+            }
+        }
+    })
+}
+
+/// Emits the where clause and fields of a generated struct in the model's struct style.
+fn struct_body(
+    style: darling::ast::Style,
+    where_clause: Option<&syn::WhereClause>,
+    fields: &[ViewField],
+) -> proc_macro2::TokenStream {
+    if style == darling::ast::Style::Tuple {
+        quote! { ( #(#fields,)* ) #where_clause ; }
+    } else {
+        quote! { #where_clause { #(#fields,)* } }
+    }
+}
@@ -0,0 +1,59 @@
+//! Applying patches to models.
+//!
+//! [`ApplyPatch`] updates a value in place from its Patch view. Plain values are
+//! replaced, while models generated with `#[views(patch_apply)]` apply nested patches
+//! field by field.
+
+use crate::{View, ViewModePatch};
+
+/// Applies a Patch view to the value it was derived from.
+///
+/// Leaf values (all types with a trivial [`View`] mapping) are replaced by the patch.
+/// For an `Option<T>`, `None` clears the value, while `Some(patch)` is applied to the
+/// existing value, or to `T::default()` if there is none.
+///
+/// ```rust
+/// use model_views::ApplyPatch;
+///
+/// let mut value = Some(1);
+/// value.apply_patch(Some(2));
+/// assert_eq!(value, Some(2));
+///
+/// value.apply_patch(None);
+/// assert_eq!(value, None);
+/// ```
+pub trait ApplyPatch: View<ViewModePatch> {
+    /// Applies `patch` to `self`
+    fn apply_patch(&mut self, patch: <Self as View<ViewModePatch>>::Type);
+}
+
+impl<T> ApplyPatch for Option<T>
+where
+    T: ApplyPatch + Default,
+{
+    fn apply_patch(&mut self, patch: Self::Type) {
+        match (self.as_mut(), patch) {
+            (_, None) => *self = None,
+            (Some(current), Some(patch)) => current.apply_patch(patch),
+            (None, Some(patch)) => self.get_or_insert_with(T::default).apply_patch(patch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_option() {
+        let mut value: Option<String> = None;
+        value.apply_patch(Some("a".to_string()));
+        assert_eq!(value.as_deref(), Some("a"));
+
+        value.apply_patch(Some("b".to_string()));
+        assert_eq!(value.as_deref(), Some("b"));
+
+        value.apply_patch(None);
+        assert_eq!(value, None);
+    }
+}
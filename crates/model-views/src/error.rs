@@ -0,0 +1,179 @@
+//! Error types for conversions between models and their views.
+
+use core::convert::Infallible;
+use core::fmt;
+
+/// Error returned when a view lacks fields required to build the target type.
+///
+/// Nested fields are reported as dot-separated paths, e.g. `author.name`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct MissingFieldsError {
+    fields: Vec<String>,
+}
+
+impl MissingFieldsError {
+    /// Creates an error for a single missing field
+    pub fn field(name: impl Into<String>) -> Self {
+        Self {
+            fields: vec![name.into()],
+        }
+    }
+
+    /// Returns the paths of all missing fields
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    /// Records `field` as missing if `value` is `None`, passing the value through
+    #[doc(hidden)]
+    pub fn require<T>(&mut self, field: &str, value: Option<T>) -> Option<T> {
+        if value.is_none() {
+            self.fields.push(field.to_string());
+        }
+        value
+    }
+
+    /// Records the missing fields of a failed nested conversion under `field`
+    #[doc(hidden)]
+    pub fn nested<T, E>(&mut self, field: &str, result: Result<T, E>) -> Option<T>
+    where
+        E: Into<Self>,
+    {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                let err: Self = err.into();
+                if err.fields.is_empty() {
+                    self.fields.push(field.to_string());
+                }
+                self.fields.extend(
+                    err.fields
+                        .into_iter()
+                        .map(|inner| format!("{field}.{inner}")),
+                );
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Display for MissingFieldsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing fields: {}", self.fields.join(", "))
+    }
+}
+
+impl core::error::Error for MissingFieldsError {}
+
+impl From<Infallible> for MissingFieldsError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+/// Error returned when a patch that must change something would not change anything.
+///
+/// See [`NoopPatch`](crate::NoopPatch) for when a patch counts as a no-op.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyPatch;
+
+impl fmt::Display for EmptyPatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("patch does not change anything")
+    }
+}
+
+impl core::error::Error for EmptyPatch {}
+
+/// Error returned when a view holds not exactly one of a set of mutually exclusive fields.
+///
+/// Fields are reported by their Rust names, e.g. when a create payload must provide either
+/// `email` or `phone`, but not both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExclusiveFieldsError {
+    fields: &'static [&'static str],
+    present: Vec<&'static str>,
+}
+
+impl ExclusiveFieldsError {
+    /// Returns the names of all mutually exclusive fields
+    pub const fn fields(&self) -> &'static [&'static str] {
+        self.fields
+    }
+
+    /// Returns the names of the fields that were present, which is empty if none was
+    pub fn present(&self) -> &[&'static str] {
+        &self.present
+    }
+
+    /// Fails unless exactly one of `fields` is present, given their presence in order
+    #[doc(hidden)]
+    pub fn check(fields: &'static [&'static str], present: &[bool]) -> Result<(), Self> {
+        let present: Vec<_> = fields
+            .iter()
+            .zip(present)
+            .filter_map(|(field, present)| present.then_some(*field))
+            .collect();
+        if present.len() == 1 {
+            Ok(())
+        } else {
+            Err(Self { fields, present })
+        }
+    }
+}
+
+impl fmt::Display for ExclusiveFieldsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected exactly one of {}, ", self.fields.join(", "))?;
+        if self.present.is_empty() {
+            f.write_str("found none")
+        } else {
+            write!(f, "found {}", self.present.join(", "))
+        }
+    }
+}
+
+impl core::error::Error for ExclusiveFieldsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collects_fields() {
+        let mut err = MissingFieldsError::default();
+        assert_eq!(err.require("id", Some(1)), Some(1));
+        assert_eq!(err.require::<u64>("name", None), None);
+        assert_eq!(
+            err.nested::<u64, _>("author", Err(MissingFieldsError::field("email"))),
+            None
+        );
+
+        assert_eq!(err.fields(), ["name", "author.email"]);
+        assert_eq!(err.to_string(), "missing fields: name, author.email");
+    }
+
+    #[test]
+    fn test_exclusive_fields() {
+        const FIELDS: &[&str] = &["email", "phone"];
+        assert_eq!(ExclusiveFieldsError::check(FIELDS, &[false, true]), Ok(()));
+
+        let Err(err) = ExclusiveFieldsError::check(FIELDS, &[true, true]) else {
+            panic!("both fields are present");
+        };
+        assert_eq!(err.present(), ["email", "phone"]);
+        assert_eq!(
+            err.to_string(),
+            "expected exactly one of email, phone, found email, phone"
+        );
+
+        let Err(err) = ExclusiveFieldsError::check(FIELDS, &[false, false]) else {
+            panic!("no field is present");
+        };
+        assert_eq!(err.fields(), FIELDS);
+        assert_eq!(
+            err.to_string(),
+            "expected exactly one of email, phone, found none"
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Converting values into their views.
+//!
+//! [`IntoView`] builds the view of a value in a given mode. Plain values are their own
+//! view, while models generated with `#[views(into_view)]` convert field by field.
+
+use crate::{View, ViewMode};
+
+/// Converts a value into its view in mode `M`.
+///
+/// Leaf values (all types with a trivial [`View`] mapping) are returned as they are, and
+/// wrappers such as `Option`, `Vec` or maps convert their contents.
+///
+/// ```rust
+/// use model_views::{IntoView, ViewModeGet};
+///
+/// let view = IntoView::<ViewModeGet>::into_view(vec![Some(1), None]);
+/// assert_eq!(view, [Some(1), None]);
+/// ```
+pub trait IntoView<M: ViewMode>: View<M> {
+    /// Converts `self` into its view
+    fn into_view(self) -> <Self as View<M>>::Type;
+}
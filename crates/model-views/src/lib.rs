@@ -133,6 +133,7 @@
 //!
 //! - `get = "required"`: Field is always present in Get view
 //! - `get = "optional"`: Field is `Option<T>` in Get view  
+//! - `get = "flatten_option"`: An `Option<T>` field stays a single `Option<T>` in Get view
 //! - `get = "forbidden"`: Field is excluded from Get view
 //!
 //! - `create = "required"`: Field must be provided when creating
@@ -141,14 +142,66 @@
 //!
 //! - `patch = "patch"`: Field is `Patch<T>` in Patch view
 //! - `patch = "optional"`: Field is `Patch<Option<T>>` in Patch view
+//! - `patch = "replace"`: Field is a plain `Option<T>` in Patch view (no ignore-vs-clear distinction)
+//! - `patch = "tri"`: Field is a [`PatchOpt<T>`] in Patch view (ignore, set to null, or set a value)
 //! - `patch = "forbidden"`: Field cannot be modified via patches
 //!
+//! Nested models resolve through `Option`, `Vec`, `Box`, maps and sets to any
+//! depth, e.g. `Option<Vec<Box<User>>>` becomes `Option<Vec<Box<UserGet>>>` in the Get view.
+//!
+//! ## Enum Fields
+//!
+//! `#[derive(Views)]` does not support enums, but an enum can still be used as a field type
+//! by implementing [`View`] for it by hand, e.g. mapping to itself in the Get and Create
+//! modes and to a hand-written patch enum in the Patch mode. A `patch = "patch"` field of
+//! that enum then becomes `Patch<ShapePatch>`. With serde, an adjacently tagged patch enum
+//! (`#[serde(tag = "kind", content = "data")]`) keeps the variant explicit:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! use model_views::{Patch, View, ViewModeCreate, ViewModeGet, ViewModePatch, Views};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! enum Shape {
+//!     Circle { radius: u32 },
+//! }
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! #[serde(tag = "kind", content = "data")]
+//! enum ShapePatch {
+//!     Circle { radius: Patch<u32> },
+//! }
+//!
+//! impl View<ViewModeGet> for Shape { type Type = Shape; }
+//! impl View<ViewModeCreate> for Shape { type Type = Shape; }
+//! impl View<ViewModePatch> for Shape { type Type = ShapePatch; }
+//!
+//! #[derive(Views)]
+//! #[views(serde)]
+//! struct Drawing {
+//!     #[views(patch = "patch")]
+//!     shape: Shape,
+//! }
+//!
+//! let patch: DrawingPatch =
+//!     serde_json::from_str(r#"{"shape":{"kind":"Circle","data":{"radius":3}}}"#).unwrap();
+//! assert!(matches!(patch.shape, Patch::Update(ShapePatch::Circle { radius: Patch::Update(3) })));
+//! # }
+//! ```
+//!
 //! # Features
 //!
 //! - **`derive`** (default): Enables the `#[derive(Views)]` procedural macro
 //! - **`serde`**: Adds `Serialize`/`Deserialize` support for `Patch<T>`
 //! - **`uuid`**: Implements `View` for `uuid::Uuid`
 //! - **`chrono`**: Implements `View` for `chrono::DateTime<Utc>`
+//! - **`compact_str`**: Implements `View` for `compact_str::CompactString`
+//! - **`smol_str`**: Implements `View` for `smol_str::SmolStr`
+//! - **`decimal`**: Implements `View` for `rust_decimal::Decimal`
+//! - **`arrayvec`**: Implements `View` for `arrayvec::ArrayVec<T, N>`
+//! - **`heapless`**: Implements `View` for `heapless::Vec<T, N>`
+//! - **`json`**: Implements `View` for `serde_json::Value` and `From<{Model}Get>` for
+//!   `serde_json::Value` on serde-enabled derives (implies `serde`)
 //!
 //! # Benefits
 //!
@@ -159,9 +212,58 @@
 
 #![forbid(unsafe_code)]
 
+mod apply;
+mod error;
+mod into_view;
+mod merge;
+mod noop;
 mod patch;
+mod patch_opt;
 
+pub use apply::*;
+pub use error::*;
+pub use into_view::*;
+pub use merge::*;
+pub use noop::NoopPatch;
 pub use patch::*;
+pub use patch_opt::*;
+
+/// Commonly used items, for glob importing.
+///
+/// ```rust
+/// use model_views::prelude::*;
+///
+/// #[derive(Views)]
+/// struct Note {
+///     #[views(get = "required", create = "required", patch = "required")]
+///     text: String,
+/// }
+///
+/// let patch = NotePatch {
+///     text: Patch::Update("Hello".to_string()),
+/// };
+/// assert!(!patch.is_noop());
+/// ```
+pub mod prelude {
+    #[cfg(feature = "derive")]
+    pub use crate::Views;
+    pub use crate::{
+        ApplyPatch, BoolPatchExt, IntoView, NoopPatch, Patch, PatchMerge, PatchOpt, View, ViewMode,
+        ViewModeCreate, ViewModeGet, ViewModeKind, ViewModePatch,
+    };
+}
+
+/// Support code for the derive macro; not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::noop::{NoopFallback, NoopProbe, NoopViaPatch};
+
+    #[cfg(feature = "serde")]
+    pub use crate::patch::serde::deserialize_non_null;
+
+    #[cfg(feature = "json")]
+    pub use serde_json;
+}
 
 #[cfg(feature = "derive")]
 pub use model_views_derive::Views;
@@ -171,24 +273,79 @@ pub trait View<M: ViewMode> {
 }
 
 /// Access mode for a model.
-pub trait ViewMode {}
+pub trait ViewMode {
+    /// Runtime discriminant of this mode
+    const KIND: ViewModeKind;
+}
+
+/// Runtime discriminant of a [`ViewMode`], e.g. for logging or metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViewModeKind {
+    /// See [`ViewModeGet`]
+    Get,
+    /// See [`ViewModeCreate`]
+    Create,
+    /// See [`ViewModePatch`]
+    Patch,
+}
+
+impl ViewModeKind {
+    /// Returns the lowercase name of the mode, i.e. `"get"`, `"create"` or `"patch"`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Create => "create",
+            Self::Patch => "patch",
+        }
+    }
+}
+
+/// Returns the lowercase name of the view mode `M`, i.e. `"get"`, `"create"` or `"patch"`.
+///
+/// ```rust
+/// use model_views::{ViewModePatch, view_type_name};
+///
+/// assert_eq!(view_type_name::<ViewModePatch>(), "patch");
+/// ```
+pub const fn view_type_name<M: ViewMode>() -> &'static str {
+    M::KIND.as_str()
+}
 
 /// Read access for a model.
 pub struct ViewModeGet;
-impl ViewMode for ViewModeGet {}
+impl ViewMode for ViewModeGet {
+    const KIND: ViewModeKind = ViewModeKind::Get;
+}
 
 /// Create access for a model.
 pub struct ViewModeCreate;
-impl ViewMode for ViewModeCreate {}
+impl ViewMode for ViewModeCreate {
+    const KIND: ViewModeKind = ViewModeKind::Create;
+}
 
 /// Update/Write access for a model.
 pub struct ViewModePatch;
-impl ViewMode for ViewModePatch {}
+impl ViewMode for ViewModePatch {
+    const KIND: ViewModeKind = ViewModeKind::Patch;
+}
 
-// Trivials just map to themselves for any mode
+// Trivials just map to themselves for any mode, and are replaced wholesale when merged
+// or patched
 macro_rules! trivial_view {
     ($($t:ty),* $(,)?) => {$(
         impl<M: $crate::ViewMode> $crate::View<M> for $t { type Type = $t; }
+
+        impl $crate::PatchMerge for $t {
+            fn merge_into(&mut self, other: Self) { *self = other; }
+        }
+
+        impl $crate::ApplyPatch for $t {
+            fn apply_patch(&mut self, patch: Self) { *self = patch; }
+        }
+
+        impl<M: $crate::ViewMode> $crate::IntoView<M> for $t {
+            fn into_view(self) -> Self { self }
+        }
     )*}
 }
 
@@ -207,11 +364,296 @@ trivial_view!(
     f32,
     f64,
     String,
-    &'static str
+    &'static str,
+    &'static [u8],
+    std::borrow::Cow<'static, str>
 );
 
+// Wrappers project their contents into the view mode, so they compose to any depth
+impl<M: ViewMode, T: View<M>> View<M> for Option<T> {
+    type Type = Option<T::Type>;
+}
+
+impl<M: ViewMode, T: View<M>> View<M> for Vec<T> {
+    type Type = Vec<T::Type>;
+}
+
+impl<M: ViewMode, T: View<M>> View<M> for Box<T> {
+    type Type = Box<T::Type>;
+}
+
+impl<M: ViewMode, T: View<M>, const N: usize> View<M> for [T; N] {
+    type Type = [T::Type; N];
+}
+
+impl<T> PatchMerge for Vec<T> {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+impl<T, const N: usize> PatchMerge for [T; N] {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+impl<T: PatchMerge> PatchMerge for Box<T> {
+    fn merge_into(&mut self, other: Self) {
+        (**self).merge_into(*other);
+    }
+}
+
+// Collections can only be replaced, which requires their elements to be leaf values
+impl<T: View<ViewModePatch, Type = T>> ApplyPatch for Vec<T> {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+impl<T: View<ViewModePatch, Type = T>, const N: usize> ApplyPatch for [T; N] {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+impl<T: ApplyPatch> ApplyPatch for Box<T> {
+    fn apply_patch(&mut self, patch: Self::Type) {
+        (**self).apply_patch(*patch);
+    }
+}
+
+// Marker fields stay as they are in every mode
+impl<M: ViewMode, T: ?Sized> View<M> for core::marker::PhantomData<T> {
+    type Type = Self;
+}
+
+impl<T: ?Sized> PatchMerge for core::marker::PhantomData<T> {
+    fn merge_into(&mut self, _other: Self) {}
+}
+
+impl<T: ?Sized> ApplyPatch for core::marker::PhantomData<T> {
+    fn apply_patch(&mut self, _patch: Self) {}
+}
+
+// Maps keep their keys and project their values into the view mode
+impl<M: ViewMode, K, V: View<M>, S> View<M> for std::collections::HashMap<K, V, S> {
+    type Type = std::collections::HashMap<K, V::Type, S>;
+}
+
+impl<M: ViewMode, K, V: View<M>> View<M> for std::collections::BTreeMap<K, V> {
+    type Type = std::collections::BTreeMap<K, V::Type>;
+}
+
+// Sets project their elements into the view mode
+impl<M: ViewMode, T: View<M>, S> View<M> for std::collections::HashSet<T, S> {
+    type Type = std::collections::HashSet<T::Type, S>;
+}
+
+impl<M: ViewMode, T: View<M>> View<M> for std::collections::BTreeSet<T> {
+    type Type = std::collections::BTreeSet<T::Type>;
+}
+
+impl<K, V, S> PatchMerge for std::collections::HashMap<K, V, S> {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+impl<K, V> PatchMerge for std::collections::BTreeMap<K, V> {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+impl<T, S> PatchMerge for std::collections::HashSet<T, S> {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+impl<T> PatchMerge for std::collections::BTreeSet<T> {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+impl<K, V: View<ViewModePatch, Type = V>, S> ApplyPatch for std::collections::HashMap<K, V, S> {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+impl<K, V: View<ViewModePatch, Type = V>> ApplyPatch for std::collections::BTreeMap<K, V> {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+impl<T: View<ViewModePatch, Type = T>, S> ApplyPatch for std::collections::HashSet<T, S> {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+impl<T: View<ViewModePatch, Type = T>> ApplyPatch for std::collections::BTreeSet<T> {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+// Wrappers convert their contents into views, keeping the wrapper
+impl<M: ViewMode, T: IntoView<M>> IntoView<M> for Option<T> {
+    fn into_view(self) -> Self::Type {
+        self.map(T::into_view)
+    }
+}
+
+impl<M: ViewMode, T: IntoView<M>> IntoView<M> for Vec<T> {
+    fn into_view(self) -> Self::Type {
+        self.into_iter().map(T::into_view).collect()
+    }
+}
+
+impl<M: ViewMode, T: IntoView<M>, const N: usize> IntoView<M> for [T; N] {
+    fn into_view(self) -> Self::Type {
+        self.map(T::into_view)
+    }
+}
+
+impl<M: ViewMode, T: IntoView<M>> IntoView<M> for Box<T> {
+    fn into_view(self) -> Self::Type {
+        Box::new((*self).into_view())
+    }
+}
+
+impl<M: ViewMode, T: ?Sized> IntoView<M> for core::marker::PhantomData<T> {
+    fn into_view(self) -> Self {
+        self
+    }
+}
+
+impl<M, K, V, S> IntoView<M> for std::collections::HashMap<K, V, S>
+where
+    M: ViewMode,
+    K: Eq + core::hash::Hash,
+    V: IntoView<M>,
+    S: core::hash::BuildHasher + Default,
+{
+    fn into_view(self) -> Self::Type {
+        self.into_iter().map(|(k, v)| (k, v.into_view())).collect()
+    }
+}
+
+impl<M: ViewMode, K: Ord, V: IntoView<M>> IntoView<M> for std::collections::BTreeMap<K, V> {
+    fn into_view(self) -> Self::Type {
+        self.into_iter().map(|(k, v)| (k, v.into_view())).collect()
+    }
+}
+
+impl<M, T, S> IntoView<M> for std::collections::HashSet<T, S>
+where
+    M: ViewMode,
+    T: IntoView<M>,
+    T::Type: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher + Default,
+{
+    fn into_view(self) -> Self::Type {
+        self.into_iter().map(T::into_view).collect()
+    }
+}
+
+impl<M, T> IntoView<M> for std::collections::BTreeSet<T>
+where
+    M: ViewMode,
+    T: IntoView<M>,
+    T::Type: Ord,
+{
+    fn into_view(self) -> Self::Type {
+        self.into_iter().map(T::into_view).collect()
+    }
+}
+
+// Fixed-capacity vectors project their elements and keep their capacity
+#[cfg(feature = "arrayvec")]
+impl<M: ViewMode, T: View<M>, const N: usize> View<M> for arrayvec::ArrayVec<T, N> {
+    type Type = arrayvec::ArrayVec<T::Type, N>;
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> PatchMerge for arrayvec::ArrayVec<T, N> {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T: View<ViewModePatch, Type = T>, const N: usize> ApplyPatch for arrayvec::ArrayVec<T, N> {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<M: ViewMode, T: IntoView<M>, const N: usize> IntoView<M> for arrayvec::ArrayVec<T, N> {
+    fn into_view(self) -> Self::Type {
+        self.into_iter().map(T::into_view).collect()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<M: ViewMode, T: View<M>, const N: usize> View<M> for heapless::Vec<T, N> {
+    type Type = heapless::Vec<T::Type, N>;
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> PatchMerge for heapless::Vec<T, N> {
+    fn merge_into(&mut self, other: Self) {
+        *self = other;
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T: View<ViewModePatch, Type = T>, const N: usize> ApplyPatch for heapless::Vec<T, N> {
+    fn apply_patch(&mut self, patch: Self) {
+        *self = patch;
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<M: ViewMode, T: IntoView<M>, const N: usize> IntoView<M> for heapless::Vec<T, N> {
+    fn into_view(self) -> Self::Type {
+        self.into_iter().map(T::into_view).collect()
+    }
+}
+
 #[cfg(feature = "uuid")]
 trivial_view!(uuid::Uuid);
 
 #[cfg(feature = "chrono")]
 trivial_view!(chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "compact_str")]
+trivial_view!(compact_str::CompactString);
+
+#[cfg(feature = "smol_str")]
+trivial_view!(smol_str::SmolStr);
+
+#[cfg(feature = "decimal")]
+trivial_view!(rust_decimal::Decimal);
+
+#[cfg(feature = "json")]
+trivial_view!(serde_json::Value);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_type_name() {
+        assert_eq!(view_type_name::<ViewModeGet>(), "get");
+        assert_eq!(view_type_name::<ViewModeCreate>(), "create");
+        assert_eq!(view_type_name::<ViewModePatch>(), "patch");
+        assert_eq!(ViewModePatch::KIND, ViewModeKind::Patch);
+    }
+}
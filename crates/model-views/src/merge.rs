@@ -0,0 +1,87 @@
+//! Recursive merging of patches.
+//!
+//! [`PatchMerge`] combines two patches into one, where updates in the later patch win.
+//! Plain values are replaced wholesale, while nested patch structs generated with
+//! `#[views(merge)]` are merged field by field.
+
+use crate::Patch;
+
+/// Merges a later patch into an earlier one.
+///
+/// For [`Patch<T>`], an `Ignore` in `other` keeps the current state, while an `Update`
+/// either replaces an `Ignore` or is merged into the existing update. Leaf values (all
+/// types with a trivial [`View`](crate::View) mapping) are simply replaced, so the last
+/// update wins.
+///
+/// ```rust
+/// use model_views::{Patch, PatchMerge};
+///
+/// let mut patch = Patch::Update(1);
+/// patch.merge_into(Patch::Ignore);
+/// assert_eq!(patch, Patch::Update(1));
+///
+/// patch.merge_into(Patch::Update(2));
+/// assert_eq!(patch, Patch::Update(2));
+/// ```
+pub trait PatchMerge {
+    /// Merges `other` into `self`, with `other` taking precedence
+    fn merge_into(&mut self, other: Self);
+}
+
+impl<T> PatchMerge for Patch<T>
+where
+    T: PatchMerge,
+{
+    fn merge_into(&mut self, other: Self) {
+        match other {
+            Self::Ignore => {}
+            Self::Update(next) => match self {
+                Self::Update(current) => current.merge_into(next),
+                Self::Ignore => *self = Self::Update(next),
+            },
+        }
+    }
+}
+
+impl<T> PatchMerge for Option<T>
+where
+    T: PatchMerge,
+{
+    fn merge_into(&mut self, other: Self) {
+        match (self.as_mut(), other) {
+            (Some(current), Some(next)) => current.merge_into(next),
+            (_, other) => *self = other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_patch() {
+        let mut patch: Patch<i32> = Patch::Ignore;
+        patch.merge_into(Patch::Ignore);
+        assert_eq!(patch, Patch::Ignore);
+
+        patch.merge_into(Patch::Update(1));
+        assert_eq!(patch, Patch::Update(1));
+
+        patch.merge_into(Patch::Ignore);
+        assert_eq!(patch, Patch::Update(1));
+
+        patch.merge_into(Patch::Update(2));
+        assert_eq!(patch, Patch::Update(2));
+    }
+
+    #[test]
+    fn test_merge_option() {
+        let mut patch = Patch::Update(Some(1));
+        patch.merge_into(Patch::Update(None));
+        assert_eq!(patch, Patch::Update(None));
+
+        patch.merge_into(Patch::Update(Some(2)));
+        assert_eq!(patch, Patch::Update(Some(2)));
+    }
+}
@@ -0,0 +1,64 @@
+//! Detection of patches that would not change anything.
+
+/// A patch that may turn out to not change anything.
+///
+/// Implemented by every generated Patch view: a patch is a no-op when all of its fields
+/// are ignored, or only carry nested patches that are no-ops themselves. With serde
+/// enabled, such fields are skipped when serializing, keeping PATCH payloads minimal.
+pub trait NoopPatch {
+    /// Returns `true` if applying this patch would not change anything
+    fn is_noop(&self) -> bool;
+}
+
+/// Wraps a patch field value to check whether it is a no-op.
+///
+/// Method resolution on `(&NoopProbe(value)).is_noop()` picks [`NoopViaPatch`] if the
+/// value implements [`NoopPatch`] and falls back to [`NoopFallback`] (never a no-op)
+/// otherwise, without requiring a trait bound on plain values.
+#[doc(hidden)]
+pub struct NoopProbe<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait NoopViaPatch {
+    fn is_noop(&self) -> bool;
+}
+
+impl<T> NoopViaPatch for NoopProbe<'_, T>
+where
+    T: NoopPatch,
+{
+    fn is_noop(&self) -> bool {
+        self.0.is_noop()
+    }
+}
+
+#[doc(hidden)]
+pub trait NoopFallback {
+    fn is_noop(&self) -> bool;
+}
+
+impl<T> NoopFallback for &NoopProbe<'_, T> {
+    fn is_noop(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Empty;
+
+    impl NoopPatch for Empty {
+        fn is_noop(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    #[allow(clippy::needless_borrow)] // the borrow selects the fallback
+    fn test_probe_dispatch() {
+        assert!((&NoopProbe(&Empty)).is_noop());
+        assert!(!(&NoopProbe(&42)).is_noop());
+    }
+}
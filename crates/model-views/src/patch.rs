@@ -7,7 +7,16 @@
 ///
 /// This is useful in PATCH-style updates where some fields should be updated while others
 /// remain unchanged. Unlike `Option`, `Patch` makes the intent to ignore a value explicit.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+///
+/// With serde enabled, `Patch<T>` serializes like `Option<T>`: inside a struct, an `Ignore`
+/// field becomes a present `null` rather than being left out. Hand-written patch structs
+/// can omit ignored fields with `#[serde(skip_serializing_if = "Patch::serialize_skip")]`,
+/// which the derived Patch views do automatically.
+///
+/// When deserializing, `null` is `Ignore` unless `T` itself can represent `null`: a
+/// `Patch<Option<T>>` deserializes `null` as `Update(None)`, so that it round-trips as long
+/// as `Ignore` is skipped rather than serialized.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum Patch<T> {
     /// Explicitly indicates that the existing value should remain unchanged
     #[default]
@@ -27,10 +36,186 @@ impl<T> Patch<T> {
         Self::Ignore
     }
 
+    /// Returns `Patch::Ignore`, the `Default` of every `Patch`, usable in const contexts
+    ///
+    /// Fields of hand-written patch structs should be marked `#[serde(default)]` so that
+    /// an absent field deserializes as `Ignore`, just like in the derived Patch views.
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// const UNCHANGED: Patch<i32> = Patch::default_ignore();
+    /// assert_eq!(UNCHANGED, Patch::default());
+    /// ```
+    pub const fn default_ignore() -> Self {
+        Self::Ignore
+    }
+
+    /// Creates a new `Patch::Update` variant with the value returned by `f`
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// let patch = Patch::update_with(|| "Alice".to_uppercase());
+    /// assert_eq!(patch, Patch::Update("ALICE".to_string()));
+    /// ```
+    pub fn update_with<F>(f: F) -> Self
+    where
+        F: FnOnce() -> T,
+    {
+        Self::Update(f())
+    }
+
+    /// Creates a `Patch::Update` with the given value if `cond` is `true`, otherwise `Patch::Ignore`
+    pub fn from_bool(cond: bool, value: T) -> Self {
+        if cond {
+            Self::Update(value)
+        } else {
+            Self::Ignore
+        }
+    }
+
+    /// Creates a `Patch::Update` with `f` applied to the value if `opt` is `Some`,
+    /// otherwise `Patch::Ignore`
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// let patch = Patch::from_option_with(Some(42), |n: i32| n.to_string());
+    /// assert_eq!(patch, Patch::Update("42".to_string()));
+    /// ```
+    pub fn from_option_with<U, F>(opt: Option<U>, f: F) -> Self
+    where
+        F: FnOnce(U) -> T,
+    {
+        opt.map_or(Self::Ignore, |value| Self::Update(f(value)))
+    }
+
     pub const fn is_ignore(&self) -> bool {
         matches!(self, Self::Ignore)
     }
 
+    /// Returns the number of contained values, i.e. `0` for `Ignore` and `1` for `Update`
+    pub const fn count(&self) -> usize {
+        match self {
+            Self::Update(_) => 1,
+            Self::Ignore => 0,
+        }
+    }
+
+    /// Returns a slice of the contained value, with one element for `Update` and none for
+    /// `Ignore`, like [`Option::as_slice`]
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// assert_eq!(Patch::Update(1).as_slice(), [1]);
+    /// assert!(Patch::<i32>::Ignore.as_slice().is_empty());
+    /// ```
+    pub const fn as_slice(&self) -> &[T] {
+        match self {
+            Self::Update(value) => core::slice::from_ref(value),
+            Self::Ignore => &[],
+        }
+    }
+
+    /// Returns `true` if the patch contains no value; an alias of [`Patch::is_ignore`]
+    pub const fn is_empty(&self) -> bool {
+        self.is_ignore()
+    }
+
+    /// Returns `true` if the patch is an update whose value satisfies `f`
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// assert!(Patch::Update(String::new()).is_update_and(|s| s.is_empty()));
+    /// assert!(!Patch::<String>::Ignore.is_update_and(|s| s.is_empty()));
+    /// ```
+    pub fn is_update_and<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        match self {
+            Self::Update(value) => f(value),
+            Self::Ignore => false,
+        }
+    }
+
+    /// Returns `true` if the patch is `Ignore` or an update whose value satisfies `f`
+    pub fn is_ignore_or<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        match self {
+            Self::Update(value) => f(value),
+            Self::Ignore => true,
+        }
+    }
+
+    /// Folds the update into `acc` with `f`, returning `acc` unchanged for `Ignore`
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// let changes = Patch::Update("name").fold_update(Vec::new(), |mut acc, field| {
+    ///     acc.push(field);
+    ///     acc
+    /// });
+    /// assert_eq!(changes, ["name"]);
+    /// ```
+    pub fn fold_update<A, F>(self, acc: A, f: F) -> A
+    where
+        F: FnOnce(A, T) -> A,
+    {
+        match self {
+            Self::Update(value) => f(acc, value),
+            Self::Ignore => acc,
+        }
+    }
+
+    /// Collapses a sequence of patches into one, where the last update wins
+    ///
+    /// Ignored patches leave the result as it was, so an empty or all-ignored sequence
+    /// reduces to `Patch::Ignore`.
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// let patch = Patch::reduce([Patch::Update(1), Patch::Update(2), Patch::Ignore]);
+    /// assert_eq!(patch, Patch::Update(2));
+    /// ```
+    pub fn reduce<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        iter.into_iter().fold(
+            Self::Ignore,
+            |acc, next| if next.is_ignore() { acc } else { next },
+        )
+    }
+
+    /// Returns `true` if `patch` is `Patch::Ignore`, for use with serde's
+    /// `skip_serializing_if`
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "serde")] {
+    /// use model_views::Patch;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct UserPatch {
+    ///     #[serde(skip_serializing_if = "Patch::serialize_skip")]
+    ///     name: Patch<String>,
+    /// }
+    ///
+    /// let json = serde_json::to_string(&UserPatch { name: Patch::Ignore }).unwrap();
+    /// assert_eq!(json, "{}");
+    /// # }
+    /// ```
+    pub const fn serialize_skip(patch: &Self) -> bool {
+        patch.is_ignore()
+    }
+
     /// Returns a new `Patch` that references the inner value without taking ownership
     pub const fn as_ref(&self) -> Patch<&T> {
         match self {
@@ -65,6 +250,162 @@ impl<T> Patch<T> {
             Self::Ignore => None,
         }
     }
+
+    /// Returns `other` if `self` is `Patch::Update`, otherwise `Patch::Ignore`, in the
+    /// spirit of [`Option::and`]
+    pub fn and<U>(self, other: Patch<U>) -> Patch<U> {
+        match self {
+            Self::Update(_) => other,
+            Self::Ignore => Patch::Ignore,
+        }
+    }
+
+    /// Keeps an existing update, or turns a `Patch::Ignore` into an update with `value`
+    pub fn ensure_update(self, value: T) -> Self {
+        match self {
+            Self::Update(_) => self,
+            Self::Ignore => Self::Update(value),
+        }
+    }
+
+    /// Discards the patch, returning `Patch::Ignore` regardless of its state
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// let locked = true;
+    /// let patch = Patch::Update("new name");
+    /// let patch = if locked { patch.clear() } else { patch };
+    /// assert_eq!(patch, Patch::Ignore);
+    /// ```
+    pub fn clear(self) -> Self {
+        Self::Ignore
+    }
+
+    /// Turns a `Patch::Ignore` into an update with the value returned by `f`, then returns
+    /// a mutable reference to the updated value
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if let Self::Ignore = self {
+            *self = Self::Update(f());
+        }
+        match self {
+            Self::Update(value) => value,
+            Self::Ignore => unreachable!("patch was just updated"),
+        }
+    }
+
+    /// Turns a `Patch::Ignore` into an update with `T::default()`, then returns a mutable
+    /// reference to the updated value
+    pub fn get_or_insert_default(&mut self) -> &mut T
+    where
+        T: Default,
+    {
+        self.get_or_insert_with(T::default)
+    }
+
+    /// Returns the updated value, or a clone of `default` for `Patch::Ignore`
+    pub fn unwrap_or_clone(self, default: &T) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            Self::Update(value) => value,
+            Self::Ignore => default.clone(),
+        }
+    }
+
+    /// Calls `f` if the patch is `Patch::Ignore`, returning the patch unchanged
+    pub fn ignore_then<F>(self, f: F) -> Self
+    where
+        F: FnOnce(),
+    {
+        if self.is_ignore() {
+            f();
+        }
+        self
+    }
+
+    /// Applies `f` to the updated value, or returns `U::default()` for `Patch::Ignore`
+    pub fn map_or_default<U, F>(self, f: F) -> U
+    where
+        U: Default,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Self::Update(value) => f(value),
+            Self::Ignore => U::default(),
+        }
+    }
+
+    /// Applies the fallible `f` to the updated value, passing `Patch::Ignore` through
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by `f`.
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// let patch = Patch::Update("42").try_map(str::parse::<u32>);
+    /// assert_eq!(patch, Ok(Patch::Update(42)));
+    /// assert!(Patch::Update("x").try_map(str::parse::<u32>).is_err());
+    /// ```
+    pub fn try_map<U, E, F>(self, f: F) -> Result<Patch<U>, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        match self {
+            Self::Update(value) => f(value).map(Patch::Update),
+            Self::Ignore => Ok(Patch::Ignore),
+        }
+    }
+}
+
+impl<T> Patch<&T> {
+    /// Maps a `Patch<&T>` to a `Patch<T>` by cloning the referenced value
+    pub fn cloned(self) -> Patch<T>
+    where
+        T: Clone,
+    {
+        match self {
+            Self::Update(value) => Patch::Update(value.clone()),
+            Self::Ignore => Patch::Ignore,
+        }
+    }
+
+    /// Maps a `Patch<&T>` to a `Patch<T>` by copying the referenced value
+    pub const fn copied(self) -> Patch<T>
+    where
+        T: Copy,
+    {
+        match self {
+            Self::Update(&value) => Patch::Update(value),
+            Self::Ignore => Patch::Ignore,
+        }
+    }
+}
+
+/// Extension trait for building a [`Patch`] from a condition, in the spirit of [`bool::then_some`].
+///
+/// ```rust
+/// use model_views::{BoolPatchExt, Patch};
+///
+/// let name_changed = true;
+/// assert_eq!(name_changed.then_patch("Alice"), Patch::Update("Alice"));
+/// assert_eq!(false.then_patch("Alice"), Patch::Ignore);
+/// ```
+pub trait BoolPatchExt {
+    /// Returns `Patch::Update(value)` if `self` is `true`, otherwise `Patch::Ignore`
+    fn then_patch<T>(self, value: T) -> Patch<T>;
+}
+
+impl BoolPatchExt for bool {
+    fn then_patch<T>(self, value: T) -> Patch<T> {
+        Patch::from_bool(self, value)
+    }
 }
 
 impl<T> From<Patch<T>> for Option<T> {
@@ -79,6 +420,21 @@ impl<T> From<Option<T>> for Patch<T> {
     }
 }
 
+impl<T> From<Result<T, ()>> for Patch<T> {
+    fn from(value: Result<T, ()>) -> Self {
+        value.map_or_else(|()| Self::Ignore, |value| Self::Update(value))
+    }
+}
+
+impl<T> From<Patch<T>> for Result<T, ()> {
+    fn from(value: Patch<T>) -> Self {
+        match value {
+            Patch::Update(value) => Ok(value),
+            Patch::Ignore => Err(()),
+        }
+    }
+}
+
 impl<T> PartialEq<Option<T>> for Patch<T>
 where
     T: PartialEq,
@@ -92,11 +448,273 @@ where
     }
 }
 
+/// Compares an update against a bare value; `Ignore` never equals a value.
+///
+/// ```rust
+/// use model_views::Patch;
+///
+/// assert_eq!(Patch::Update(42), 42);
+/// assert_ne!(Patch::Ignore, 42);
+/// ```
+///
+/// For a `Patch<Option<T>>`, as generated for `patch = "optional"` fields, a bare `None`
+/// matches both this impl and `PartialEq<Option<T>>`, so `patch == None` no longer
+/// infers. Spell out the intended comparison instead:
+///
+/// ```rust
+/// use model_views::Patch;
+///
+/// let patch: Patch<Option<u32>> = Patch::Update(None);
+/// assert!(patch == Patch::Update(None));
+/// assert!(Patch::<Option<u32>>::Ignore.is_ignore());
+/// ```
+impl<T> PartialEq<T> for Patch<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &T) -> bool {
+        match self {
+            Self::Update(value) => value == other,
+            Self::Ignore => false,
+        }
+    }
+}
+
+/// A [`Patch`] that serializes `Ignore` as an explicit `null`.
+///
+/// This is the behavior of `Patch<T>` itself, spelled out at the type level for APIs
+/// that expect every field to be present. `null` deserializes back into `Ignore`.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use model_views::{ExplicitNull, Patch};
+///
+/// #[derive(serde::Serialize)]
+/// struct Request {
+///     name: ExplicitNull<String>,
+/// }
+///
+/// let json = serde_json::to_string(&Request { name: ExplicitNull(Patch::Ignore) }).unwrap();
+/// assert_eq!(json, r#"{"name":null}"#);
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExplicitNull<T>(pub Patch<T>);
+
+/// A [`Patch`] whose `Ignore` is omitted rather than serialized.
+///
+/// Fields of this type are meant to be marked with
+/// `#[serde(default, skip_serializing_if = "OmitIgnore::is_ignore")]`: an ignored field is
+/// left out, and an absent field deserializes as `Ignore`. Since `null` is not taken for
+/// `Ignore`, it deserializes as an update with a `null` value, e.g. `Update(None)` for an
+/// `OmitIgnore<Option<T>>`. Serializing an `Ignore` that is not skipped is an error.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use model_views::{OmitIgnore, Patch};
+///
+/// #[derive(serde::Serialize)]
+/// struct Request {
+///     #[serde(default, skip_serializing_if = "OmitIgnore::is_ignore")]
+///     name: OmitIgnore<Option<String>>,
+/// }
+///
+/// let json = serde_json::to_string(&Request { name: OmitIgnore(Patch::Ignore) }).unwrap();
+/// assert_eq!(json, "{}");
+///
+/// let json = serde_json::to_string(&Request { name: OmitIgnore(Patch::Update(None)) }).unwrap();
+/// assert_eq!(json, r#"{"name":null}"#);
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OmitIgnore<T>(pub Patch<T>);
+
+/// A [`Patch`] serialized as an operation object, for JSON-Patch-like protocols.
+///
+/// With serde enabled, `Ignore` is represented as `{"op":"ignore"}` and `Update(value)` as
+/// `{"op":"set","value":value}`; both forms deserialize back into the same patch. Any other
+/// `op`, or a `set` without a `value`, is rejected.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use model_views::{OpPatch, Patch};
+///
+/// let json = serde_json::to_string(&OpPatch(Patch::Update(1))).unwrap();
+/// assert_eq!(json, r#"{"op":"set","value":1}"#);
+///
+/// let patch: OpPatch<i32> = serde_json::from_str(r#"{"op":"ignore"}"#).unwrap();
+/// assert_eq!(patch, OpPatch(Patch::Ignore));
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpPatch<T>(pub Patch<T>);
+
+/// A [`Patch`] serialized like an adjacently tagged enum, for protocols built on serde's
+/// `#[serde(tag = "t", content = "c")]` convention.
+///
+/// With serde enabled, `Ignore` is represented as `{"t":"Ignore"}`, without a content key,
+/// and `Update(value)` as `{"t":"Update","c":value}`. Unlike [`OpPatch`], the tag and
+/// content keys may appear in either order when deserializing. Any other tag, or an
+/// `Update` without content, is rejected.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use model_views::{AdjTaggedPatch, Patch};
+///
+/// let json = serde_json::to_string(&AdjTaggedPatch(Patch::Update(1))).unwrap();
+/// assert_eq!(json, r#"{"t":"Update","c":1}"#);
+///
+/// let patch: AdjTaggedPatch<i32> = serde_json::from_str(r#"{"t":"Ignore"}"#).unwrap();
+/// assert_eq!(patch, AdjTaggedPatch(Patch::Ignore));
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AdjTaggedPatch<T>(pub Patch<T>);
+
+/// A [`Patch`] encoded as a string, where a marker string stands for `Ignore`.
+///
+/// This is meant for form-encoded or other string-only transports, where a client sends a
+/// literal marker to leave a value untouched. With serde enabled, the marker deserializes
+/// as `Ignore` and any other string is parsed with [`FromStr`](core::str::FromStr) into an
+/// update; serializing writes the marker or the value's [`Display`](core::fmt::Display)
+/// output. The marker is `M::MARKER`, `"__ignore__"` by default; implement
+/// [`IgnoreMarker`] on a unit struct to use another one.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use model_views::{IgnoreMarker, Patch, StringSentinelPatch};
+///
+/// let patch: StringSentinelPatch<u32> = serde_json::from_str(r#""42""#).unwrap();
+/// assert_eq!(patch.0, Patch::Update(42));
+///
+/// #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+/// struct Keep;
+///
+/// impl IgnoreMarker for Keep {
+///     const MARKER: &'static str = "keep";
+/// }
+///
+/// let patch: StringSentinelPatch<u32, Keep> = serde_json::from_str(r#""keep""#).unwrap();
+/// assert_eq!(patch.0, Patch::Ignore);
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StringSentinelPatch<T, M = DefaultIgnoreMarker>(
+    pub Patch<T>,
+    pub core::marker::PhantomData<M>,
+);
+
+/// The marker string standing for `Ignore` in a [`StringSentinelPatch`].
+pub trait IgnoreMarker {
+    const MARKER: &'static str;
+}
+
+/// The default [`IgnoreMarker`], `"__ignore__"`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DefaultIgnoreMarker;
+
+impl IgnoreMarker for DefaultIgnoreMarker {
+    const MARKER: &'static str = "__ignore__";
+}
+
+impl<T, M> StringSentinelPatch<T, M> {
+    pub const fn new(patch: Patch<T>) -> Self {
+        Self(patch, core::marker::PhantomData)
+    }
+}
+
+impl<T, M> From<Patch<T>> for StringSentinelPatch<T, M> {
+    fn from(value: Patch<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> OmitIgnore<T> {
+    pub const fn is_ignore(&self) -> bool {
+        self.0.is_ignore()
+    }
+}
+
+impl<T> From<Patch<T>> for ExplicitNull<T> {
+    fn from(value: Patch<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<Patch<T>> for OmitIgnore<T> {
+    fn from(value: Patch<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<Patch<T>> for OpPatch<T> {
+    fn from(value: Patch<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<Patch<T>> for AdjTaggedPatch<T> {
+    fn from(value: Patch<T>) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "json")]
+impl Patch<serde_json::Value> {
+    /// Creates an update to the JSON representation of `value`, e.g. for building patches
+    /// against loosely typed payloads
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` cannot be represented as JSON, which can only happen for maps with
+    /// non-string keys or failing `Serialize` impls.
+    ///
+    /// ```rust
+    /// use model_views::Patch;
+    ///
+    /// let patch = Patch::update_json(&[1, 2]);
+    /// assert_eq!(patch, Patch::Update(serde_json::json!([1, 2])));
+    /// ```
+    pub fn update_json<T>(value: &T) -> Self
+    where
+        T: ::serde::Serialize + ?Sized,
+    {
+        Self::Update(serde_json::to_value(value).expect("value serializes to JSON"))
+    }
+
+    /// Returns `true` if the patch is an update to JSON `null`
+    pub const fn is_json_null(&self) -> bool {
+        matches!(self, Self::Update(serde_json::Value::Null))
+    }
+}
+
 #[cfg(feature = "serde")]
-mod serde {
-    use super::Patch;
+pub(crate) mod serde {
+    use super::{
+        AdjTaggedPatch, ExplicitNull, IgnoreMarker, OmitIgnore, OpPatch, Patch, StringSentinelPatch,
+    };
+    use serde::de::IntoDeserializer;
+    use serde::de::value::UnitDeserializer;
     use serde::{Deserialize, Serialize};
 
+    /// Deserializes a present value into `Patch::Update`, rejecting an explicit `null`
+    /// instead of treating it as `Patch::Ignore`.
+    #[doc(hidden)]
+    pub fn deserialize_non_null<'de, D, T>(deserializer: D) -> Result<Patch<T>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        Option::<T>::deserialize(deserializer)?.map_or_else(
+            || {
+                Err(serde::de::Error::custom(
+                    "explicit null is not allowed; omit the field to leave it unchanged",
+                ))
+            },
+            |v| Ok(Patch::Update(v)),
+        )
+    }
+
     impl<T> Serialize for Patch<T>
     where
         T: Serialize,
@@ -120,9 +738,185 @@ mod serde {
         where
             D: serde::Deserializer<'de>,
         {
-            // Delegate to Option<T> and map back into Patch<T>
+            // Delegate to Option<T>; `null` then updates types that can represent it, such
+            // as `Option<T>`, so that `Patch<Option<T>>` keeps `Update(None)` apart from
+            // `Ignore`, and leaves the value unchanged for all other types
+            Ok(Option::<T>::deserialize(deserializer)?.map_or_else(
+                || {
+                    let null: UnitDeserializer<serde::de::value::Error> = ().into_deserializer();
+                    T::deserialize(null).map_or(Self::Ignore, Self::Update)
+                },
+                Self::Update,
+            ))
+        }
+    }
+
+    impl<T> Serialize for ExplicitNull<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for ExplicitNull<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            // `null` is always `Ignore` here, even if `T` could represent it
             let opt = Option::<T>::deserialize(deserializer)?;
-            Ok(opt.map_or_else(|| Self::Ignore, |v| Self::Update(v)))
+            Ok(Self(opt.into()))
+        }
+    }
+
+    impl<T> Serialize for OmitIgnore<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match &self.0 {
+                Patch::Update(v) => v.serialize(serializer),
+                Patch::Ignore => Err(serde::ser::Error::custom(
+                    "OmitIgnore::Ignore must be skipped with `skip_serializing_if`",
+                )),
+            }
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for OmitIgnore<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            // A present field is always an update, absence is handled by `default`
+            T::deserialize(deserializer).map(|v| Self(Patch::Update(v)))
+        }
+    }
+
+    impl<T, M> Serialize for StringSentinelPatch<T, M>
+    where
+        T: core::fmt::Display,
+        M: IgnoreMarker,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match &self.0 {
+                Patch::Update(value) => serializer.collect_str(value),
+                Patch::Ignore => serializer.serialize_str(M::MARKER),
+            }
+        }
+    }
+
+    impl<'de, T, M> Deserialize<'de> for StringSentinelPatch<T, M>
+    where
+        T: core::str::FromStr,
+        T::Err: core::fmt::Display,
+        M: IgnoreMarker,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let value = String::deserialize(deserializer)?;
+            if value == M::MARKER {
+                return Ok(Self::new(Patch::Ignore));
+            }
+            value
+                .parse()
+                .map(|value| Self::new(Patch::Update(value)))
+                .map_err(serde::de::Error::custom)
+        }
+    }
+
+    /// The wire format of [`OpPatch`], tagged by its `op` field
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "op", rename_all = "lowercase")]
+    enum Op<T> {
+        Ignore,
+        Set { value: T },
+    }
+
+    impl<T> Serialize for OpPatch<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match &self.0 {
+                Patch::Ignore => Op::<&T>::Ignore.serialize(serializer),
+                Patch::Update(value) => Op::Set { value }.serialize(serializer),
+            }
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for OpPatch<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            Ok(Self(match Op::<T>::deserialize(deserializer)? {
+                Op::Ignore => Patch::Ignore,
+                Op::Set { value } => Patch::Update(value),
+            }))
+        }
+    }
+
+    /// The wire format of [`AdjTaggedPatch`], tagged by `t` next to the content `c`
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "t", content = "c")]
+    enum Adjacent<T> {
+        Ignore,
+        Update(T),
+    }
+
+    impl<T> Serialize for AdjTaggedPatch<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match &self.0 {
+                Patch::Ignore => Adjacent::<&T>::Ignore.serialize(serializer),
+                Patch::Update(value) => Adjacent::Update(value).serialize(serializer),
+            }
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for AdjTaggedPatch<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            Ok(Self(match Adjacent::<T>::deserialize(deserializer)? {
+                Adjacent::Ignore => Patch::Ignore,
+                Adjacent::Update(value) => Patch::Update(value),
+            }))
         }
     }
 }
@@ -140,6 +934,15 @@ mod tests {
         assert!(matches!(ignore, Patch::Ignore));
     }
 
+    #[test]
+    fn test_count_and_is_empty() {
+        assert_eq!(Patch::Update(42).count(), 1);
+        assert_eq!(Patch::<i32>::Ignore.count(), 0);
+
+        assert!(!Patch::Update(42).is_empty());
+        assert!(Patch::<i32>::Ignore.is_empty());
+    }
+
     #[test]
     fn test_option_conversions() {
         let update: Patch<i32> = Patch::update(42);
@@ -152,6 +955,292 @@ mod tests {
         assert_eq!(Patch::from(None::<i32>), Patch::Ignore);
     }
 
+    #[test]
+    fn test_copy() {
+        fn assert_copy<T: Copy>() {}
+        assert_copy::<Patch<i32>>();
+
+        let patch = Patch::Update(1);
+        let copy = patch;
+        assert_eq!(patch, copy);
+    }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut calls = 0;
+        let mut patch = Patch::Ignore;
+        *patch.get_or_insert_with(|| {
+            calls += 1;
+            1
+        }) += 1;
+        assert_eq!(patch, Patch::Update(2));
+
+        patch.get_or_insert_with(|| {
+            calls += 1;
+            10
+        });
+        assert_eq!(patch, Patch::Update(2));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_get_or_insert_default() {
+        let mut patch = Patch::<Vec<i32>>::Ignore;
+        patch.get_or_insert_default().push(1);
+        assert_eq!(patch, Patch::Update(vec![1]));
+
+        patch.get_or_insert_default().push(2);
+        assert_eq!(patch, Patch::Update(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_unwrap_or_clone() {
+        let current = "current".to_string();
+        assert_eq!(
+            Patch::Update("new".to_string()).unwrap_or_clone(&current),
+            "new"
+        );
+        assert_eq!(Patch::Ignore.unwrap_or_clone(&current), "current");
+    }
+
+    #[test]
+    fn test_ignore_then() {
+        let mut ignored = 0;
+        assert_eq!(
+            Patch::Update(1).ignore_then(|| ignored += 1),
+            Patch::Update(1)
+        );
+        assert_eq!(ignored, 0);
+        assert_eq!(
+            Patch::<i32>::Ignore.ignore_then(|| ignored += 1),
+            Patch::Ignore
+        );
+        assert_eq!(ignored, 1);
+    }
+
+    #[test]
+    fn test_map_or_default() {
+        assert_eq!(Patch::Update("abc").map_or_default(str::len), 3);
+        assert_eq!(Patch::<&str>::Ignore.map_or_default(str::len), 0);
+        assert_eq!(
+            Patch::<i32>::Ignore.map_or_default(|value| value.to_string()),
+            String::new()
+        );
+    }
+
+    #[test]
+    fn test_try_map() {
+        let parse = |value: &str| value.parse::<u8>();
+        assert_eq!(Patch::Update("7").try_map(parse), Ok(Patch::Update(7)));
+        assert!(Patch::Update("300").try_map(parse).is_err());
+
+        let mut calls = 0;
+        let patch = Patch::<&str>::Ignore.try_map(|value| {
+            calls += 1;
+            parse(value)
+        });
+        assert_eq!(patch, Ok(Patch::Ignore));
+        assert_eq!(calls, 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_ignore_in_struct() {
+        #[derive(::serde::Serialize)]
+        struct Plain {
+            name: Patch<String>,
+        }
+
+        #[derive(::serde::Serialize)]
+        struct Skipping {
+            #[serde(skip_serializing_if = "Patch::serialize_skip")]
+            name: Patch<String>,
+        }
+
+        let json = serde_json::to_string(&Plain {
+            name: Patch::Ignore,
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"name":null}"#);
+
+        let json = serde_json::to_string(&Skipping {
+            name: Patch::Ignore,
+        })
+        .unwrap();
+        assert_eq!(json, "{}");
+
+        let json = serde_json::to_string(&Skipping {
+            name: Patch::Update("Alice".to_string()),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"name":"Alice"}"#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_default_is_ignore() {
+        #[derive(::serde::Deserialize)]
+        struct Request {
+            #[serde(default)]
+            count: Patch<i32>,
+        }
+
+        let request: Request = serde_json::from_str("{}").unwrap();
+        assert_eq!(request.count, Patch::default_ignore());
+
+        let request: Request = serde_json::from_str(r#"{"count":3}"#).unwrap();
+        assert_eq!(request.count, Patch::Update(3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_nested_option_round_trip() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Request {
+            #[serde(default, skip_serializing_if = "Patch::serialize_skip")]
+            name: Patch<Option<String>>,
+        }
+
+        for (name, expected) in [
+            (Patch::Ignore, "{}"),
+            (Patch::Update(None), r#"{"name":null}"#),
+            (
+                Patch::Update(Some("Alice".to_string())),
+                r#"{"name":"Alice"}"#,
+            ),
+        ] {
+            let request = Request { name };
+            let json = serde_json::to_string(&request).unwrap();
+            assert_eq!(json, expected);
+            assert_eq!(serde_json::from_str::<Request>(&json).unwrap(), request);
+        }
+
+        // Without an `Option` to hold it, `null` still means "leave unchanged"
+        let patch: Patch<i32> = serde_json::from_str("null").unwrap();
+        assert_eq!(patch, Patch::Ignore);
+        let patch: Patch<Option<i32>> = serde_json::from_str("null").unwrap();
+        assert_eq!(patch, Patch::Update(None));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_explicit_null_round_trip() {
+        let json = serde_json::to_string(&ExplicitNull::<i32>(Patch::Ignore)).unwrap();
+        assert_eq!(json, "null");
+        let patch: ExplicitNull<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(patch, ExplicitNull(Patch::Ignore));
+
+        let json = serde_json::to_string(&ExplicitNull(Patch::Update(1))).unwrap();
+        assert_eq!(json, "1");
+        let patch: ExplicitNull<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(patch, ExplicitNull(Patch::Update(1)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_omit_ignore_round_trip() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Request {
+            #[serde(default, skip_serializing_if = "OmitIgnore::is_ignore")]
+            name: OmitIgnore<Option<String>>,
+        }
+
+        for name in [
+            Patch::Ignore,
+            Patch::Update(None),
+            Patch::Update(Some("Alice".to_string())),
+        ] {
+            let request = Request {
+                name: OmitIgnore(name),
+            };
+            let json = serde_json::to_string(&request).unwrap();
+            assert_eq!(serde_json::from_str::<Request>(&json).unwrap(), request);
+        }
+
+        assert!(serde_json::to_string(&OmitIgnore::<i32>(Patch::Ignore)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_string_sentinel_patch() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct Skip;
+
+        impl IgnoreMarker for Skip {
+            const MARKER: &'static str = "-";
+        }
+
+        let patch: StringSentinelPatch<i32> = serde_json::from_str(r#""__ignore__""#).unwrap();
+        assert_eq!(patch.0, Patch::Ignore);
+        let patch: StringSentinelPatch<i32> = serde_json::from_str(r#""-5""#).unwrap();
+        assert_eq!(patch.0, Patch::Update(-5));
+        assert!(serde_json::from_str::<StringSentinelPatch<i32>>(r#""five""#).is_err());
+
+        let patch: StringSentinelPatch<i32, Skip> = serde_json::from_str(r#""-""#).unwrap();
+        assert_eq!(patch.0, Patch::Ignore);
+        let patch: StringSentinelPatch<String, Skip> =
+            serde_json::from_str(r#""__ignore__""#).unwrap();
+        assert_eq!(patch.0, Patch::Update("__ignore__".to_string()));
+
+        let json = serde_json::to_string(&StringSentinelPatch::<i32>::new(Patch::Ignore)).unwrap();
+        assert_eq!(json, r#""__ignore__""#);
+        let json = serde_json::to_string(&StringSentinelPatch::<i32, Skip>::from(Patch::Update(7)))
+            .unwrap();
+        assert_eq!(json, r#""7""#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_op_patch_round_trip() {
+        let json = serde_json::to_string(&OpPatch::<i32>(Patch::Ignore)).unwrap();
+        assert_eq!(json, r#"{"op":"ignore"}"#);
+        let patch: OpPatch<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(patch, OpPatch(Patch::Ignore));
+
+        let json = serde_json::to_string(&OpPatch(Patch::Update(Some("a")))).unwrap();
+        assert_eq!(json, r#"{"op":"set","value":"a"}"#);
+        let patch: OpPatch<Option<String>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(patch, OpPatch(Patch::Update(Some("a".to_string()))));
+
+        let patch: OpPatch<Option<i32>> =
+            serde_json::from_str(r#"{"op":"set","value":null}"#).unwrap();
+        assert_eq!(patch, OpPatch(Patch::Update(None)));
+
+        assert!(serde_json::from_str::<OpPatch<i32>>(r#"{"op":"remove"}"#).is_err());
+        assert!(serde_json::from_str::<OpPatch<i32>>(r#"{"op":"set"}"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_adj_tagged_patch_round_trip() {
+        let json = serde_json::to_string(&AdjTaggedPatch::<i32>(Patch::Ignore)).unwrap();
+        assert_eq!(json, r#"{"t":"Ignore"}"#);
+        let patch: AdjTaggedPatch<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(patch, AdjTaggedPatch(Patch::Ignore));
+
+        let json = serde_json::to_string(&AdjTaggedPatch(Patch::Update("a"))).unwrap();
+        assert_eq!(json, r#"{"t":"Update","c":"a"}"#);
+        let patch: AdjTaggedPatch<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(patch, AdjTaggedPatch(Patch::Update("a".to_string())));
+
+        let patch: AdjTaggedPatch<i32> = serde_json::from_str(r#"{"c":2,"t":"Update"}"#).unwrap();
+        assert_eq!(patch, AdjTaggedPatch(Patch::Update(2)));
+
+        assert!(serde_json::from_str::<AdjTaggedPatch<i32>>(r#"{"t":"Remove"}"#).is_err());
+        assert!(serde_json::from_str::<AdjTaggedPatch<i32>>(r#"{"t":"Update"}"#).is_err());
+    }
+
+    #[test]
+    fn test_result_conversions() {
+        let update: Patch<i32> = Ok(42).into();
+        let ignore: Patch<i32> = Err(()).into();
+        assert_eq!(update, Patch::Update(42));
+        assert_eq!(ignore, Patch::Ignore);
+
+        assert_eq!(Result::<i32, ()>::from(update), Ok(42));
+        assert_eq!(Result::<i32, ()>::from(ignore), Err(()));
+    }
+
     #[test]
     fn test_reference_operations() {
         let update = Patch::update(42);
@@ -164,6 +1253,116 @@ mod tests {
         assert_eq!(ignore.as_option_ref(), None);
     }
 
+    #[test]
+    fn test_cloned_and_copied() {
+        let update = Patch::update("foo".to_string());
+        let ignore: Patch<String> = Patch::ignore();
+
+        assert_eq!(update.as_ref().cloned(), update);
+        assert_eq!(ignore.as_ref().cloned(), Patch::Ignore);
+
+        let update = Patch::update(42);
+        let ignore: Patch<i32> = Patch::ignore();
+
+        assert_eq!(update.as_ref().copied(), Patch::Update(42));
+        assert_eq!(ignore.as_ref().copied(), Patch::Ignore);
+    }
+
+    #[test]
+    fn test_from_bool() {
+        assert_eq!(Patch::from_bool(true, 42), Patch::Update(42));
+        assert_eq!(Patch::from_bool(false, 42), Patch::Ignore);
+
+        assert_eq!(true.then_patch(42), Patch::Update(42));
+        assert_eq!(false.then_patch(42), Patch::Ignore);
+    }
+
+    #[test]
+    fn test_from_option_with() {
+        assert_eq!(
+            Patch::from_option_with(Some(2), |n: i32| n * 10),
+            Patch::Update(20)
+        );
+        assert_eq!(
+            Patch::from_option_with(None, |n: i32| n * 10),
+            Patch::Ignore
+        );
+    }
+
+    #[test]
+    fn test_and() {
+        assert_eq!(Patch::Update(1).and(Patch::Update("a")), Patch::Update("a"));
+        assert_eq!(Patch::Update(1).and(Patch::<&str>::Ignore), Patch::Ignore);
+        assert_eq!(Patch::<i32>::Ignore.and(Patch::Update("a")), Patch::Ignore);
+        assert_eq!(
+            Patch::<i32>::Ignore.and(Patch::<&str>::Ignore),
+            Patch::Ignore
+        );
+    }
+
+    #[test]
+    fn test_ensure_update() {
+        assert_eq!(Patch::Update(1).ensure_update(2), Patch::Update(1));
+        assert_eq!(Patch::Ignore.ensure_update(2), Patch::Update(2));
+    }
+
+    #[test]
+    fn test_clear() {
+        assert_eq!(Patch::Update(1).clear(), Patch::Ignore);
+        assert_eq!(Patch::<i32>::Ignore.clear(), Patch::Ignore);
+    }
+
+    #[test]
+    fn test_is_update_and() {
+        assert!(Patch::Update(2).is_update_and(|n| n % 2 == 0));
+        assert!(!Patch::Update(3).is_update_and(|n| n % 2 == 0));
+        assert!(!Patch::<i32>::Ignore.is_update_and(|_| true));
+    }
+
+    #[test]
+    fn test_is_ignore_or() {
+        assert!(Patch::Update(2).is_ignore_or(|n| n % 2 == 0));
+        assert!(!Patch::Update(3).is_ignore_or(|n| n % 2 == 0));
+        assert!(Patch::<i32>::Ignore.is_ignore_or(|_| false));
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let update = Patch::Update("a".to_string());
+        assert_eq!(update.as_slice().len(), update.count());
+        assert_eq!(update.as_slice(), ["a".to_string()]);
+
+        let ignore = Patch::<String>::Ignore;
+        assert_eq!(ignore.as_slice().len(), ignore.count());
+    }
+
+    #[test]
+    fn test_fold_update() {
+        let push = |mut acc: Vec<i32>, value| {
+            acc.push(value);
+            acc
+        };
+        let audit = Patch::Update(1).fold_update(Vec::new(), push);
+        let audit = Patch::Ignore.fold_update(audit, push);
+        let audit = Patch::Update(3).fold_update(audit, push);
+        assert_eq!(audit, [1, 3]);
+    }
+
+    #[test]
+    fn test_reduce() {
+        let patch = Patch::reduce([Patch::Ignore, Patch::Update(1), Patch::Ignore]);
+        assert_eq!(patch, Patch::Update(1));
+
+        let patch = Patch::reduce([Patch::Update(1), Patch::Ignore, Patch::Update(3)]);
+        assert_eq!(patch, Patch::Update(3));
+
+        let patch = Patch::<i32>::reduce([Patch::Ignore, Patch::Ignore]);
+        assert_eq!(patch, Patch::Ignore);
+
+        let patch = Patch::<i32>::reduce(std::iter::empty());
+        assert_eq!(patch, Patch::Ignore);
+    }
+
     #[test]
     #[allow(clippy::default_trait_access)]
     fn test_default() {
@@ -181,4 +1380,20 @@ mod tests {
         assert_eq!(ignore, None);
         assert_ne!(ignore, Some(42));
     }
+
+    #[test]
+    fn test_equality_with_value() {
+        assert_eq!(Patch::Update(42), 42);
+        assert_ne!(Patch::Update(41), 42);
+        assert_ne!(Patch::<i32>::Ignore, 42);
+    }
+
+    #[test]
+    fn test_equality_of_optional_updates() {
+        // A bare `None` is ambiguous for `Patch<Option<T>>`, so the variant is spelled out
+        let cleared: Patch<Option<i32>> = Patch::Update(None);
+        assert_eq!(cleared, Patch::Update(None));
+        assert_ne!(cleared, Patch::Ignore);
+        assert_eq!(Patch::Update(Some(42)), Some(42));
+    }
 }
@@ -0,0 +1,138 @@
+//! Provides a three-state update for nullable values.
+//! The `PatchOpt` enum names the states of a `Patch<Option<T>>` explicitly: leave the
+//! value untouched, clear it, or set it.
+
+use crate::{Patch, PatchMerge};
+
+/// Represents a potential update to a nullable value.
+///
+/// This is equivalent to `Patch<Option<T>>`, but spells out the three states instead of
+/// nesting `Option` inside `Patch`. With serde enabled, an absent field deserializes as
+/// `Ignore` (when the field is marked `#[serde(default)]`), `null` as `SetNull`, and any
+/// other value as `SetValue`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum PatchOpt<T> {
+    /// Explicitly indicates that the existing value should remain unchanged
+    #[default]
+    Ignore,
+    /// Indicates that the existing value should be cleared
+    SetNull,
+    /// Contains a value that should replace the existing value
+    SetValue(T),
+}
+
+impl<T> PatchOpt<T> {
+    pub const fn is_ignore(&self) -> bool {
+        matches!(self, Self::Ignore)
+    }
+
+    /// Converts the `PatchOpt` into the equivalent `Patch<Option<T>>`
+    pub fn into_patch(self) -> Patch<Option<T>> {
+        match self {
+            Self::Ignore => Patch::Ignore,
+            Self::SetNull => Patch::Update(None),
+            Self::SetValue(value) => Patch::Update(Some(value)),
+        }
+    }
+}
+
+impl<T> From<Patch<Option<T>>> for PatchOpt<T> {
+    fn from(value: Patch<Option<T>>) -> Self {
+        match value {
+            Patch::Ignore => Self::Ignore,
+            Patch::Update(None) => Self::SetNull,
+            Patch::Update(Some(value)) => Self::SetValue(value),
+        }
+    }
+}
+
+impl<T> From<PatchOpt<T>> for Patch<Option<T>> {
+    fn from(value: PatchOpt<T>) -> Self {
+        value.into_patch()
+    }
+}
+
+impl<T> PatchMerge for PatchOpt<T>
+where
+    T: PatchMerge,
+{
+    fn merge_into(&mut self, other: Self) {
+        match (self, other) {
+            (_, Self::Ignore) => {}
+            (Self::SetValue(current), Self::SetValue(next)) => current.merge_into(next),
+            (this, other) => *this = other,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::PatchOpt;
+    use serde::{Deserialize, Serialize};
+
+    impl<T> Serialize for PatchOpt<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            // `Ignore` has no representation of its own; skip it with
+            // `skip_serializing_if = "PatchOpt::is_ignore"` to keep it distinct from `null`
+            match self {
+                Self::SetValue(v) => serializer.serialize_some(v),
+                Self::Ignore | Self::SetNull => serializer.serialize_none(),
+            }
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for PatchOpt<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            // A present field is either `null` or a value; absence is handled by `default`
+            let opt = Option::<T>::deserialize(deserializer)?;
+            Ok(opt.map_or_else(|| Self::SetNull, |v| Self::SetValue(v)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patch_conversions() {
+        assert_eq!(PatchOpt::<i32>::Ignore.into_patch(), Patch::Ignore);
+        assert_eq!(PatchOpt::<i32>::SetNull.into_patch(), Patch::Update(None));
+        assert_eq!(PatchOpt::SetValue(1).into_patch(), Patch::Update(Some(1)));
+
+        assert_eq!(
+            PatchOpt::from(Patch::Update(Some(1))),
+            PatchOpt::SetValue(1)
+        );
+        assert_eq!(
+            PatchOpt::<i32>::from(Patch::Update(None)),
+            PatchOpt::SetNull
+        );
+        assert_eq!(PatchOpt::<i32>::from(Patch::Ignore), PatchOpt::Ignore);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut patch = PatchOpt::SetValue(1);
+        patch.merge_into(PatchOpt::Ignore);
+        assert_eq!(patch, PatchOpt::SetValue(1));
+
+        patch.merge_into(PatchOpt::SetNull);
+        assert_eq!(patch, PatchOpt::SetNull);
+
+        patch.merge_into(PatchOpt::SetValue(2));
+        assert_eq!(patch, PatchOpt::SetValue(2));
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec_views {
+    use arrayvec::ArrayVec;
+    use model_views::{Patch, Views};
+
+    #[derive(Debug, Views)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    #[derive(Debug, Views)]
+    pub struct Polygon {
+        #[views(get = "required", create = "required", patch = "patch")]
+        pub points: ArrayVec<Point, 8>,
+    }
+
+    #[test]
+    fn array_vec_projects_its_elements() {
+        let mut points = ArrayVec::<PointGet, 8>::new();
+        points.push(PointGet { x: 1, y: 2 });
+        let get = PolygonGet { points };
+        assert_eq!(get.points.capacity(), 8);
+        assert_eq!(get.points[0].x, 1);
+
+        let patch = PolygonPatch {
+            points: Patch::Update(ArrayVec::<PointPatch, 8>::new()),
+        };
+        assert!(matches!(patch.points, Patch::Update(points) if points.is_empty()));
+    }
+}
+
+#[cfg(feature = "heapless")]
+pub mod heapless_views {
+    use model_views::{Patch, Views};
+
+    #[derive(Debug, Views)]
+    pub struct Reading {
+        pub value: u16,
+    }
+
+    #[derive(Debug, Views)]
+    pub struct Sensor {
+        #[views(get = "required", create = "required", patch = "patch")]
+        pub readings: heapless::Vec<Reading, 4>,
+    }
+
+    #[test]
+    fn heapless_vec_projects_its_elements() {
+        let mut readings = heapless::Vec::<ReadingGet, 4>::new();
+        assert!(readings.push(ReadingGet { value: 7 }).is_ok());
+        let get = SensorGet { readings };
+        assert_eq!(get.readings.capacity(), 4);
+        assert_eq!(get.readings[0].value, 7);
+
+        let patch = SensorPatch {
+            readings: Patch::Update(heapless::Vec::<ReadingPatch, 4>::new()),
+        };
+        assert!(matches!(patch.readings, Patch::Update(readings) if readings.is_empty()));
+    }
+}
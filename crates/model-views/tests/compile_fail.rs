@@ -0,0 +1,12 @@
+#[test]
+fn invalid_derives_fail_to_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn invalid_serde_derives_fail_to_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui-serde/*.rs");
+}
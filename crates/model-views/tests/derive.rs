@@ -56,3 +56,1501 @@ fn it_works() {
         },
     };
 }
+
+#[derive(Debug, Views)]
+#[cfg_attr(feature = "serde", views(serde = true))]
+#[cfg_attr(not(feature = "serde"), views(serde = false))]
+pub struct ReplaceModel {
+    #[views(get = "required", create = "required", patch = "replace")]
+    pub title: String,
+    #[views(get = "required", create = "required", patch = "required")]
+    pub body: String,
+}
+
+#[test]
+fn replace_policy_uses_plain_option() {
+    let patch = ReplaceModelPatch {
+        title: Some("foo".to_string()),
+        body: Patch::Ignore,
+    };
+    assert_eq!(patch.title.as_deref(), Some("foo"));
+
+    let empty = ReplaceModelPatch::default();
+    assert_eq!(empty.title, None);
+}
+
+#[derive(Debug, Views)]
+#[views(
+    create_validate = "validate_account_create",
+    create_validate_error = "&'static str",
+    patch_validate = "validate_account_patch",
+    patch_validate_error = "&'static str"
+)]
+pub struct Account {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub handle: String,
+}
+
+fn validate_account_create(account: &AccountCreate) -> Result<(), &'static str> {
+    if account.handle.is_empty() {
+        Err("handle must not be empty")
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_account_patch(account: &AccountPatch) -> Result<(), &'static str> {
+    match &account.handle {
+        Patch::Update(handle) if handle.is_empty() => Err("handle must not be empty"),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn validate_hooks_delegate_to_functions() {
+    let valid = AccountCreate {
+        handle: "alice".to_string(),
+    };
+    assert_eq!(valid.validate(), Ok(()));
+
+    let invalid = AccountCreate {
+        handle: String::new(),
+    };
+    assert_eq!(invalid.validate(), Err("handle must not be empty"));
+
+    assert_eq!(AccountPatch::default().validate(), Ok(()));
+    let invalid = AccountPatch {
+        handle: Patch::Update(String::new()),
+    };
+    assert_eq!(invalid.validate(), Err("handle must not be empty"));
+}
+
+#[derive(Debug, PartialEq, Views)]
+#[views(get_try_into)]
+pub struct CachedAuthor {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "optional")]
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq, Views)]
+#[views(get_try_into)]
+pub struct CachedPost {
+    #[views(get = "required")]
+    pub title: String,
+    #[views(get = "required")]
+    pub author: CachedAuthor,
+}
+
+#[test]
+fn get_view_converts_back_into_model() {
+    let view = CachedPostGet {
+        title: "foo".to_string(),
+        author: CachedAuthorGet {
+            id: 1,
+            name: Some("bar".to_string()),
+        },
+    };
+
+    assert_eq!(
+        CachedPost::try_from(view),
+        Ok(CachedPost {
+            title: "foo".to_string(),
+            author: CachedAuthor {
+                id: 1,
+                name: "bar".to_string(),
+            },
+        })
+    );
+}
+
+#[test]
+fn get_view_reports_missing_optional_fields() {
+    let view = CachedPostGet {
+        title: "foo".to_string(),
+        author: CachedAuthorGet { id: 1, name: None },
+    };
+
+    let err = CachedPost::try_from(view).unwrap_err();
+    assert_eq!(err.fields(), ["author.name"]);
+}
+
+#[derive(Debug, Views)]
+#[views(merge)]
+pub struct MergeAuthor {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub name: String,
+    #[views(get = "required", create = "required", patch = "required")]
+    pub email: String,
+}
+
+#[derive(Debug, Views)]
+#[views(merge)]
+pub struct MergePost {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub title: String,
+    #[views(get = "required", create = "required", patch = "optional")]
+    pub author: MergeAuthor,
+}
+
+#[test]
+fn merge_recurses_into_nested_patches() {
+    use model_views::PatchMerge;
+
+    let mut patch = MergePostPatch {
+        title: Patch::Update("first".to_string()),
+        author: Patch::Update(Some(MergeAuthorPatch {
+            name: Patch::Update("Alice".to_string()),
+            email: Patch::Ignore,
+        })),
+    };
+
+    patch.merge_into(MergePostPatch {
+        title: Patch::Ignore,
+        author: Patch::Update(Some(MergeAuthorPatch {
+            name: Patch::Ignore,
+            email: Patch::Update("alice@example.com".to_string()),
+        })),
+    });
+
+    assert_eq!(patch.title, Patch::Update("first".to_string()));
+    let Patch::Update(Some(author)) = patch.author else {
+        panic!("expected a nested author patch");
+    };
+    assert_eq!(author.name, Patch::Update("Alice".to_string()));
+    assert_eq!(author.email, Patch::Update("alice@example.com".to_string()));
+}
+
+#[derive(Debug, Views)]
+#[views(merge)]
+pub struct MergeLabel {
+    #[views(get = "required", create = "required", patch = "replace")]
+    pub text: String,
+}
+
+#[test]
+fn merge_keeps_replace_fields_left_unset() {
+    use model_views::PatchMerge;
+
+    let mut patch = MergeLabelPatch {
+        text: Some("first".to_string()),
+    };
+    patch.merge_into(MergeLabelPatch { text: None });
+    assert_eq!(patch.text.as_deref(), Some("first"));
+
+    patch.merge_into(MergeLabelPatch {
+        text: Some("second".to_string()),
+    });
+    assert_eq!(patch.text.as_deref(), Some("second"));
+}
+
+#[derive(Debug, Views)]
+#[views(patch_normalize = "normalize_tag_patch")]
+pub struct Tag {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub label: String,
+}
+
+fn normalize_tag_patch(patch: &mut TagPatch) {
+    if let Patch::Update(label) = &mut patch.label {
+        *label = label.trim().to_lowercase();
+        if label.is_empty() {
+            patch.label = Patch::Ignore;
+        }
+    }
+}
+
+#[test]
+fn normalize_hook_cleans_up_patch() {
+    let patch = TagPatch {
+        label: Patch::Update("  Rust ".to_string()),
+    };
+    assert_eq!(patch.normalized().label, Patch::Update("rust".to_string()));
+
+    let patch = TagPatch {
+        label: Patch::Update("   ".to_string()),
+    };
+    assert_eq!(patch.normalized().label, Patch::Ignore);
+}
+
+#[derive(Debug, Views)]
+pub struct Address {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub city: String,
+}
+
+#[derive(Debug, Views)]
+pub struct Resident {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub addresses: Option<Vec<Box<Address>>>,
+    #[views(get = "required", create = "optional", patch = "optional")]
+    pub previous: Vec<Option<Address>>,
+}
+
+/// Compiles only if `T` resolves to the view type `V` in mode `M`
+fn assert_view_type<T, M, V>()
+where
+    T: model_views::View<M, Type = V>,
+    M: model_views::ViewMode,
+{
+}
+
+#[test]
+fn nested_wrappers_resolve_view_types() {
+    use model_views::{ViewModeCreate, ViewModeGet, ViewModePatch};
+
+    assert_view_type::<Option<Vec<Box<Address>>>, ViewModeGet, Option<Vec<Box<AddressGet>>>>();
+    assert_view_type::<Option<Vec<Box<Address>>>, ViewModeCreate, Option<Vec<Box<AddressCreate>>>>();
+    assert_view_type::<Option<Vec<Box<Address>>>, ViewModePatch, Option<Vec<Box<AddressPatch>>>>();
+    assert_view_type::<Box<Option<u64>>, ViewModePatch, Box<Option<u64>>>();
+    assert_view_type::<
+        std::collections::HashSet<Address>,
+        ViewModeGet,
+        std::collections::HashSet<AddressGet>,
+    >();
+    assert_view_type::<
+        Option<std::collections::BTreeSet<Box<Address>>>,
+        ViewModePatch,
+        Option<std::collections::BTreeSet<Box<AddressPatch>>>,
+    >();
+    assert_view_type::<
+        std::collections::BTreeSet<String>,
+        ViewModeCreate,
+        std::collections::BTreeSet<String>,
+    >();
+
+    let get = ResidentGet {
+        addresses: Some(vec![Box::new(AddressGet {
+            city: "Berlin".to_string(),
+        })]),
+        previous: vec![None],
+    };
+    assert_eq!(get.addresses.unwrap()[0].city, "Berlin");
+
+    let create = ResidentCreate {
+        addresses: None,
+        previous: Some(vec![Some(AddressCreate {
+            city: "Paris".to_string(),
+        })]),
+    };
+    assert!(create.addresses.is_none());
+
+    let patch = ResidentPatch {
+        addresses: Patch::Update(Some(vec![Box::new(AddressPatch {
+            city: Patch::Update("Rome".to_string()),
+        })])),
+        previous: Patch::Update(None),
+    };
+    assert!(matches!(patch.addresses, Patch::Update(Some(_))));
+}
+
+#[derive(Debug, PartialEq, Views)]
+#[views(get_try_into)]
+pub struct Contact {
+    #[views(get = "required")]
+    pub name: String,
+    #[views(get = "flatten_option")]
+    pub phone: Option<String>,
+    #[views(get = "flatten_option")]
+    pub address: Option<CachedAuthor>,
+}
+
+#[test]
+fn flatten_option_yields_single_option() {
+    let view = ContactGet {
+        name: "Alice".to_string(),
+        phone: None,
+        address: Some(CachedAuthorGet {
+            id: 1,
+            name: Some("Bob".to_string()),
+        }),
+    };
+    let phone: &Option<String> = &view.phone;
+    assert!(phone.is_none());
+
+    assert_eq!(
+        Contact::try_from(view),
+        Ok(Contact {
+            name: "Alice".to_string(),
+            phone: None,
+            address: Some(CachedAuthor {
+                id: 1,
+                name: "Bob".to_string(),
+            }),
+        })
+    );
+
+    let view = ContactGet {
+        name: "Alice".to_string(),
+        phone: Some("123".to_string()),
+        address: Some(CachedAuthorGet { id: 1, name: None }),
+    };
+    assert_eq!(
+        Contact::try_from(view).unwrap_err().fields(),
+        ["address.name"]
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(create_introspect)]
+pub struct Signup {
+    #[views(create = "required")]
+    pub email: String,
+    #[views(create = "optional")]
+    pub nickname: String,
+    #[views(create = "optional")]
+    pub referrer: String,
+}
+
+#[test]
+fn create_introspection_lists_missing_optionals() {
+    let create = SignupCreate {
+        email: "alice@example.com".to_string(),
+        nickname: None,
+        referrer: Some("bob".to_string()),
+    };
+    assert_eq!(create.missing_optionals(), ["nickname"]);
+
+    let create = SignupCreate {
+        email: "alice@example.com".to_string(),
+        nickname: None,
+        referrer: None,
+    };
+    assert_eq!(create.missing_optionals(), ["nickname", "referrer"]);
+}
+
+#[derive(Debug, PartialEq, Views)]
+#[views(get_try_into, get_ref, merge)]
+pub struct Column {
+    #[views(field = "display_name")]
+    pub dsp_nm: String,
+    #[views(field = "width", get = "optional")]
+    pub col_w: u32,
+}
+
+#[test]
+fn renamed_fields_map_back_to_model() {
+    let view = ColumnGet {
+        display_name: "Name".to_string(),
+        width: Some(20),
+    };
+    let borrowed = view.as_ref_view();
+    assert_eq!(borrowed.display_name, "Name");
+    assert_eq!(borrowed.width, Some(20));
+
+    assert_eq!(
+        Column::try_from(view),
+        Ok(Column {
+            dsp_nm: "Name".to_string(),
+            col_w: 20,
+        })
+    );
+
+    let view = ColumnGet {
+        display_name: "Name".to_string(),
+        width: None,
+    };
+    assert_eq!(Column::try_from(view).unwrap_err().fields(), ["width"]);
+
+    let _create = ColumnCreate {
+        display_name: "Name".to_string(),
+        width: 20,
+    };
+    let _patch = ColumnPatch {
+        display_name: Patch::Ignore,
+        width: Patch::Update(10),
+    };
+}
+
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+pub struct Square(pub f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+#[derive(Views)]
+pub struct Drawing {
+    #[views(opaque, create = "required", patch = "required")]
+    pub shape: Box<dyn Shape>,
+    #[views(get_type = "f64", create = "forbidden", patch = "forbidden")]
+    pub scale: u32,
+}
+
+#[test]
+fn opaque_trait_object_fields_keep_their_type() {
+    let get = DrawingGet {
+        shape: Box::new(Square(2.0)),
+        scale: 0.5,
+    };
+    assert!((get.shape.area() - 4.0).abs() < f64::EPSILON);
+
+    let create = DrawingCreate {
+        shape: Box::new(Square(3.0)),
+    };
+    assert!((create.shape.area() - 9.0).abs() < f64::EPSILON);
+
+    let patch = DrawingPatch {
+        shape: Patch::Update(Box::new(Square(1.0))),
+    };
+    assert!(matches!(patch.shape, Patch::Update(_)));
+}
+
+/// Stands in for a third-party type without `View` or `PatchMerge` impls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Money {
+    pub cents: i64,
+}
+
+#[derive(Debug, Views)]
+#[views(merge)]
+pub struct Invoice {
+    #[views(opaque, get = "required", create = "required", patch = "required")]
+    pub total: Money,
+    #[views(opaque, get = "optional", create = "optional", patch = "optional")]
+    pub discount: Money,
+    #[views(opaque, get = "required", create = "forbidden", patch = "replace")]
+    pub tax: Money,
+}
+
+#[test]
+fn opaque_fields_use_types_without_view_impls() {
+    use model_views::PatchMerge;
+
+    let get = InvoiceGet {
+        total: Money { cents: 100 },
+        discount: None,
+        tax: Money { cents: 7 },
+    };
+    assert_eq!(get.total, Money { cents: 100 });
+
+    let create = InvoiceCreate {
+        total: Money { cents: 100 },
+        discount: Some(Money { cents: 10 }),
+    };
+    assert_eq!(create.discount, Some(Money { cents: 10 }));
+
+    let mut patch = InvoicePatch {
+        total: Patch::Update(Money { cents: 120 }),
+        discount: Patch::Ignore,
+        tax: Some(Money { cents: 8 }),
+    };
+    patch.merge_into(InvoicePatch {
+        total: Patch::Ignore,
+        discount: Patch::Update(None),
+        tax: Some(Money { cents: 9 }),
+    });
+    assert_eq!(patch.total, Patch::Update(Money { cents: 120 }));
+    assert_eq!(patch.discount, Patch::Update(None));
+    assert_eq!(patch.tax, Some(Money { cents: 9 }));
+}
+
+#[derive(Debug, PartialEq, Views)]
+#[views(patch_assert)]
+pub struct Setting {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "required", create = "required", patch = "required")]
+    pub key: String,
+    #[views(get = "required", create = "required", patch = "replace")]
+    pub value: String,
+}
+
+#[test]
+fn patch_assert_checks_model_states() {
+    let before = Setting {
+        id: 1,
+        key: "theme".to_string(),
+        value: "light".to_string(),
+    };
+    let after = Setting {
+        id: 1,
+        key: "theme".to_string(),
+        value: "dark".to_string(),
+    };
+
+    let patch = SettingPatch {
+        key: Patch::Ignore,
+        value: Some("dark".to_string()),
+    };
+    assert!(patch.applies_to(&before, &after));
+    assert!(!patch.applies_to(&before, &before));
+
+    let patch = SettingPatch::default();
+    assert!(patch.applies_to(&before, &before));
+    assert!(!patch.applies_to(&before, &after));
+}
+
+mod reexport {
+    pub use model_views as views;
+}
+
+#[derive(Debug, Views)]
+#[views(crate = "crate::reexport::views")]
+pub struct Reexported {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub name: String,
+}
+
+#[test]
+fn crate_path_can_be_overridden() {
+    let _patch = ReexportedPatch {
+        name: Patch::Update("foo".to_string()),
+    };
+}
+
+#[derive(Debug, Views)]
+pub struct Keyed<K> {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "required", create = "required", patch = "required")]
+    pub key: std::marker::PhantomData<K>,
+}
+
+#[test]
+fn phantom_data_fields_need_no_annotation() {
+    use std::marker::PhantomData;
+
+    struct UserKey;
+
+    assert_view_type::<PhantomData<UserKey>, model_views::ViewModePatch, PhantomData<UserKey>>();
+
+    let get = KeyedGet::<UserKey> {
+        id: 1,
+        key: PhantomData,
+    };
+    assert_eq!(get.id, 1);
+
+    let _create = KeyedCreate::<UserKey> { key: PhantomData };
+    let _patch = KeyedPatch::<UserKey> {
+        key: Patch::Update(PhantomData),
+    };
+}
+
+#[derive(Debug, Views)]
+#[views(create_new)]
+pub struct Subscriber {
+    #[views(create = "required")]
+    pub email: String,
+    #[views(create = "optional")]
+    pub name: String,
+    #[views(create = "required")]
+    pub weekly: bool,
+}
+
+#[test]
+fn create_new_takes_required_fields_in_order() {
+    let create = SubscriberCreate::new("alice@example.com".to_string(), true);
+    assert_eq!(create.email, "alice@example.com");
+    assert_eq!(create.name, None);
+    assert!(create.weekly);
+}
+
+#[derive(Debug, Views)]
+#[views(base_name = "Widget")]
+pub struct __MangledWidget0 {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub label: String,
+}
+
+#[test]
+fn base_name_overrides_view_names() {
+    let _get = WidgetGet {
+        label: "foo".to_string(),
+    };
+    let _patch: <__MangledWidget0 as model_views::View<model_views::ViewModePatch>>::Type =
+        WidgetPatch::default();
+}
+
+#[derive(Debug, Views)]
+#[views(create_default)]
+pub struct ServerConfig {
+    #[views(create = "required")]
+    pub port: u16,
+    #[views(create = "required")]
+    pub host: String,
+    #[views(create = "optional")]
+    pub workers: u32,
+}
+
+#[test]
+fn create_default_derives_default() {
+    let create = ServerConfigCreate::default();
+    assert_eq!(create.port, 0);
+    assert_eq!(create.host, "");
+    assert_eq!(create.workers, None);
+}
+
+#[derive(Debug, Default, PartialEq, Views)]
+#[views(patch_apply)]
+pub struct Dimensions {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, PartialEq, Views)]
+#[views(patch_apply)]
+pub struct Frame {
+    #[views(patch = "patch")]
+    pub title: String,
+    #[views(patch = "patch")]
+    pub size: Dimensions,
+    #[views(patch = "optional")]
+    pub margin: Dimensions,
+    #[views(patch = "patch")]
+    pub border: Option<Dimensions>,
+}
+
+#[test]
+fn patch_apply_recurses_into_nested_models() {
+    use model_views::ApplyPatch;
+
+    let mut frame = Frame {
+        title: "Before".to_string(),
+        size: Dimensions {
+            width: 10,
+            height: 20,
+        },
+        margin: Dimensions {
+            width: 1,
+            height: 2,
+        },
+        border: None,
+    };
+
+    frame.apply_patch(FramePatch {
+        title: Patch::Ignore,
+        size: Patch::Update(DimensionsPatch {
+            width: Patch::Update(30),
+            height: Patch::Ignore,
+        }),
+        margin: Patch::Update(Some(DimensionsPatch {
+            width: Patch::Ignore,
+            height: Patch::Update(4),
+        })),
+        border: Patch::Update(Some(DimensionsPatch {
+            width: Patch::Update(5),
+            height: Patch::Ignore,
+        })),
+    });
+    assert_eq!(frame.title, "Before");
+    assert_eq!(
+        frame.size,
+        Dimensions {
+            width: 30,
+            height: 20
+        }
+    );
+    assert_eq!(
+        frame.margin,
+        Dimensions {
+            width: 1,
+            height: 4
+        }
+    );
+    assert_eq!(
+        frame.border,
+        Some(Dimensions {
+            width: 5,
+            height: 0
+        })
+    );
+
+    frame.apply_patch(FramePatch {
+        title: Patch::Update("After".to_string()),
+        margin: Patch::Update(None),
+        border: Patch::Update(None),
+        ..FramePatch::default()
+    });
+    assert_eq!(frame.title, "After");
+    assert_eq!(frame.margin, Dimensions::default());
+    assert_eq!(frame.border, None);
+}
+
+#[derive(Debug, Default, Views)]
+#[views(merge, patch_apply)]
+pub struct Category {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(get = "required", create = "optional", patch = "patch")]
+    pub parent: Option<Box<Category>>,
+}
+
+#[test]
+fn self_referential_model_views() {
+    use model_views::ApplyPatch;
+
+    let get = CategoryGet {
+        name: "Laptops".to_string(),
+        parent: Some(Box::new(CategoryGet {
+            name: "Computers".to_string(),
+            parent: None,
+        })),
+    };
+    assert_eq!(get.parent.as_ref().map(|parent| parent.name.as_str()), Some("Computers"));
+
+    let create = CategoryCreate {
+        name: "Computers".to_string(),
+        parent: None,
+    };
+    assert!(create.parent.is_none());
+
+    let mut category = Category {
+        name: "Laptops".to_string(),
+        parent: Some(Box::new(Category {
+            name: "Computers".to_string(),
+            parent: None,
+        })),
+    };
+    category.apply_patch(CategoryPatch {
+        name: Patch::Ignore,
+        parent: Patch::Update(Some(Box::new(CategoryPatch {
+            name: Patch::Update("Hardware".to_string()),
+            parent: Patch::Ignore,
+        }))),
+    });
+    assert_eq!(category.name, "Laptops");
+    assert_eq!(category.parent.map(|parent| parent.name), Some("Hardware".to_string()));
+}
+
+#[derive(Debug, Views)]
+#[views(patch_non_empty)]
+pub struct Profile {
+    #[views(patch = "patch")]
+    pub bio: String,
+    #[views(patch = "optional")]
+    pub website: String,
+}
+
+#[test]
+fn patch_non_empty_rejects_noop_patches() {
+    let Err(err) = ProfilePatch::default().require_change() else {
+        panic!("expected an empty patch to be rejected");
+    };
+    assert_eq!(err, model_views::EmptyPatch);
+
+    let patch = ProfilePatch {
+        website: Patch::Update(None),
+        ..ProfilePatch::default()
+    };
+    assert!(patch.require_change().is_ok());
+}
+
+#[derive(Debug, Views)]
+#[views(get_introspect)]
+pub struct Listing {
+    #[views(get = "required")]
+    pub id: u64,
+    #[views(get = "optional")]
+    pub summary: String,
+    #[views(get = "optional")]
+    pub thumbnail: String,
+    #[views(get = "flatten_option")]
+    pub archived_at: Option<u64>,
+}
+
+#[test]
+fn get_introspection_lists_present_optionals() {
+    let get = ListingGet {
+        id: 1,
+        summary: None,
+        thumbnail: Some("thumb.png".to_string()),
+        archived_at: Some(0),
+    };
+    assert_eq!(get.present_optionals(), ["thumbnail"]);
+
+    let get = ListingGet {
+        id: 1,
+        summary: Some("Bike".to_string()),
+        thumbnail: Some("thumb.png".to_string()),
+        archived_at: None,
+    };
+    assert_eq!(get.present_optionals(), ["summary", "thumbnail"]);
+}
+
+#[derive(Debug, Views)]
+#[view(create_new)]
+pub struct Bookmark {
+    #[view(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[view(get = "required", create = "required", patch = "optional")]
+    pub url: String,
+}
+
+#[test]
+fn singular_view_attribute_is_an_alias() {
+    let create = BookmarkCreate::new("https://example.com".to_string());
+    assert_eq!(create.url, "https://example.com");
+
+    let patch = BookmarkPatch {
+        url: Patch::Update(None),
+    };
+    assert_eq!(patch.url, Patch::Update(None));
+
+    let get = BookmarkGet {
+        id: 1,
+        url: "https://example.com".to_string(),
+    };
+    assert_eq!(get.id, 1);
+}
+
+#[derive(Debug, Views)]
+#[views(patch_to_create)]
+pub struct Owner {
+    #[views(create = "required", patch = "patch")]
+    pub name: String,
+}
+
+#[derive(Debug, Views)]
+#[views(patch_to_create)]
+pub struct Pet {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(create = "required", patch = "patch")]
+    pub name: String,
+    #[views(create = "required", patch = "optional")]
+    pub species: String,
+    #[views(create = "optional", patch = "tri")]
+    pub nickname: String,
+    #[views(create = "required", patch = "patch")]
+    pub owner: Owner,
+}
+
+#[test]
+fn patch_to_create_requires_updated_create_fields() {
+    let patch = PetPatch {
+        name: Patch::Update("Rex".to_string()),
+        species: Patch::Update(Some("dog".to_string())),
+        nickname: model_views::PatchOpt::Ignore,
+        owner: Patch::Update(OwnerPatch {
+            name: Patch::Update("Alice".to_string()),
+        }),
+    };
+    let create = PetCreate::try_from(patch).unwrap();
+    assert_eq!(create.name, "Rex");
+    assert_eq!(create.species, "dog");
+    assert_eq!(create.nickname, None);
+    assert_eq!(create.owner.name, "Alice");
+
+    let patch = PetPatch {
+        name: Patch::Update("Rex".to_string()),
+        species: Patch::Update(None),
+        nickname: model_views::PatchOpt::SetValue("Rexy".to_string()),
+        owner: Patch::Update(OwnerPatch::default()),
+    };
+    let Err(err) = PetCreate::try_from(patch) else {
+        panic!("expected missing fields");
+    };
+    assert_eq!(err.fields(), ["species", "owner.name"]);
+}
+
+pub mod encapsulated {
+    use model_views::Views;
+
+    #[derive(Debug, Views)]
+    #[views(get_accessors, create_new, create_accessors)]
+    pub struct Customer {
+        #[views(get = "required", create = "forbidden", patch = "forbidden")]
+        pub id: u64,
+        #[views(get = "optional", create = "required", patch = "patch")]
+        pub name: String,
+    }
+
+    impl CustomerGet {
+        pub const fn new(id: u64, name: Option<String>) -> Self {
+            Self { id, name }
+        }
+    }
+}
+
+#[test]
+fn accessors_expose_private_fields() {
+    use encapsulated::{CustomerCreate, CustomerGet, CustomerPatch};
+
+    let get = CustomerGet::new(7, Some("Alice".to_string()));
+    assert_eq!(*get.id(), 7);
+    assert_eq!(get.name().as_deref(), Some("Alice"));
+
+    let create = CustomerCreate::new("Bob".to_string());
+    assert_eq!(create.name(), "Bob");
+
+    // The Patch view keeps its public fields
+    let patch = CustomerPatch {
+        name: Patch::Update("Carol".to_string()),
+    };
+    assert_eq!(patch.name, "Carol".to_string());
+}
+
+#[derive(Debug, Views)]
+#[repr(C, align(16))]
+pub struct AlignedDefault {
+    pub value: u32,
+}
+
+#[derive(Debug, Views)]
+#[views(forward_repr)]
+#[repr(C, align(16))]
+pub struct AlignedForwarded {
+    pub value: u32,
+}
+
+#[test]
+fn repr_is_only_forwarded_on_request() {
+    assert_eq!(core::mem::align_of::<AlignedDefault>(), 16);
+    assert_eq!(core::mem::align_of::<AlignedDefaultGet>(), 4);
+    assert_eq!(core::mem::align_of::<AlignedDefaultPatch>(), 4);
+
+    assert_eq!(core::mem::align_of::<AlignedForwardedGet>(), 16);
+    assert_eq!(core::mem::align_of::<AlignedForwardedCreate>(), 16);
+    assert_eq!(core::mem::align_of::<AlignedForwardedPatch>(), 16);
+}
+
+#[test]
+fn field_count_matches_policies() {
+    // `Pet` has an `id` that is only present in the Get view
+    assert_eq!(PetGet::FIELD_COUNT, 5);
+    assert_eq!(PetCreate::FIELD_COUNT, 4);
+    assert_eq!(PetPatch::FIELD_COUNT, 4);
+
+    assert_eq!(BookmarkGet::FIELD_COUNT, 2);
+    assert_eq!(BookmarkCreate::FIELD_COUNT, 1);
+    assert_eq!(BookmarkPatch::FIELD_COUNT, 1);
+}
+
+#[derive(Debug, Views)]
+pub struct Attachment {
+    pub name: std::borrow::Cow<'static, str>,
+    pub magic: &'static [u8],
+    // `Vec<u8>` resolves through the `Vec<T>` impl
+    pub data: Vec<u8>,
+}
+
+#[test]
+fn string_and_byte_leaf_types_map_to_themselves() {
+    let get = AttachmentGet {
+        name: std::borrow::Cow::Borrowed("logo.png"),
+        magic: b"\x89PNG",
+        data: vec![1, 2, 3],
+    };
+    assert_eq!(get.name, "logo.png");
+
+    let patch = AttachmentPatch {
+        name: Patch::Update(std::borrow::Cow::Owned("icon.png".to_string())),
+        magic: Patch::Ignore,
+        data: Patch::Update(Vec::new()),
+    };
+    assert_eq!(patch.data, Patch::Update(Vec::new()));
+    assert_eq!(get.magic, b"\x89PNG");
+}
+
+#[derive(Debug, Clone, Views)]
+#[views(into_view)]
+pub struct Author {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Views)]
+#[views(into_view)]
+pub struct Article {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "optional", create = "optional", patch = "optional")]
+    pub title: String,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub authors: Vec<Author>,
+    #[views(get = "flatten_option", create = "required", patch = "tri")]
+    pub summary: Option<String>,
+}
+
+#[test]
+fn to_view_dispatches_over_modes() {
+    use model_views::{ViewModeCreate, ViewModeGet, ViewModePatch};
+
+    let article = Article {
+        id: 1,
+        title: "Views".to_string(),
+        authors: vec![Author {
+            id: 2,
+            name: "Alice".to_string(),
+        }],
+        summary: None,
+    };
+
+    let get = article.clone().to_view::<ViewModeGet>();
+    assert_eq!(get.id, 1);
+    assert_eq!(get.title.as_deref(), Some("Views"));
+    assert_eq!(get.authors[0].id, 2);
+    assert_eq!(get.summary, None);
+
+    let create = article.clone().to_view::<ViewModeCreate>();
+    assert_eq!(create.title.as_deref(), Some("Views"));
+    assert_eq!(create.authors[0].name, "Alice");
+    assert_eq!(create.summary, None);
+
+    let patch = article.to_view::<ViewModePatch>();
+    assert_eq!(patch.title, Patch::Update(Some("Views".to_string())));
+    assert!(matches!(&patch.authors, Patch::Update(authors) if authors[0].name == "Alice".to_string()));
+    assert_eq!(patch.summary, model_views::PatchOpt::SetValue(None));
+}
+
+#[derive(Debug, Views)]
+#[views(merge, patch_apply, into_view)]
+pub struct Buffer<const N: usize> {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub data: [u8; N],
+    #[views(get = "optional", create = "optional", patch = "optional")]
+    pub label: String,
+}
+
+#[test]
+fn const_generics_are_forwarded() {
+    use model_views::ApplyPatch;
+
+    let get: BufferGet<16> = BufferGet {
+        data: [7; 16],
+        label: None,
+    };
+    assert_eq!(get.data.len(), 16);
+    assert_eq!(BufferGet::<16>::FIELD_COUNT, 2);
+
+    let create = BufferCreate::<4> {
+        data: [1, 2, 3, 4],
+        label: Some("raw".to_string()),
+    };
+    assert_eq!(create.data, [1, 2, 3, 4]);
+
+    let mut buffer = Buffer::<4> {
+        data: create.data,
+        label: "raw".to_string(),
+    };
+    buffer.apply_patch(BufferPatch {
+        data: Patch::Update([0; 4]),
+        label: Patch::Ignore,
+    });
+    assert_eq!(buffer.data, [0; 4]);
+    assert_eq!(buffer.label, "raw");
+
+    let get = buffer.to_view::<model_views::ViewModeGet>();
+    assert_eq!(get.label.as_deref(), Some("raw"));
+}
+
+pub mod cache {
+    use model_views::Views;
+
+    #[derive(Debug, Views)]
+    #[views(
+        base_name = "User",
+        get_derive(Clone, Debug, PartialEq, Eq, Hash),
+        patch_derive(Debug, PartialEq)
+    )]
+    pub struct CachedUser {
+        #[views(get = "required", create = "forbidden", patch = "forbidden")]
+        pub id: u64,
+        #[views(get = "optional", create = "required", patch = "optional")]
+        pub nickname: String,
+    }
+}
+
+#[test]
+fn get_derive_allows_views_as_hash_keys() {
+    use cache::{UserGet, UserPatch};
+    use std::collections::HashMap;
+
+    let key = UserGet {
+        id: 1,
+        nickname: Some("al".to_string()),
+    };
+    let mut hits = HashMap::new();
+    hits.insert(key.clone(), 1);
+    *hits.entry(key.clone()).or_insert(0) += 1;
+    hits.insert(
+        UserGet {
+            id: 1,
+            nickname: None,
+        },
+        1,
+    );
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[&key], 2);
+
+    assert_eq!(UserPatch::default(), UserPatch::default());
+}
+
+pub mod seeding {
+    use model_views::Views;
+
+    #[derive(Debug, Clone, Views)]
+    #[views(create_from)]
+    pub struct Address {
+        #[views(get = "required", create = "forbidden", patch = "forbidden")]
+        pub id: u64,
+        #[views(get = "required", create = "required", patch = "patch")]
+        pub city: String,
+    }
+
+    #[derive(Debug, Clone, Views)]
+    #[views(create_from)]
+    pub struct Customer {
+        #[views(get = "required", create = "forbidden", patch = "forbidden")]
+        pub id: u64,
+        #[views(get = "required", create = "optional", patch = "patch")]
+        pub name: String,
+        #[views(get = "required", create = "required", patch = "patch")]
+        pub address: Address,
+        #[views(create_type = "u32")]
+        pub credit: u16,
+    }
+}
+
+#[test]
+fn create_from_seeds_create_view_from_model() {
+    use seeding::{Address, Customer, CustomerCreate};
+
+    let customer = Customer {
+        id: 7,
+        name: "ACME".to_string(),
+        address: Address {
+            id: 8,
+            city: "Berlin".to_string(),
+        },
+        credit: 500,
+    };
+
+    let create = CustomerCreate::from(customer);
+    assert_eq!(create.name.as_deref(), Some("ACME"));
+    assert_eq!(create.address.city, "Berlin");
+    assert_eq!(create.credit, 500_u32);
+}
+
+pub mod renamed_from {
+    use model_views::Views;
+
+    #[derive(Debug, Clone, Views)]
+    #[views(get_from, create_from, into_view)]
+    pub struct Device {
+        #[views(field = "device_id", create = "forbidden", patch = "forbidden")]
+        pub id: u64,
+        #[views(field = "label", get = "optional", create = "optional")]
+        pub name: String,
+        #[views(field = "owner", get = "flatten_option")]
+        pub owner_id: Option<u64>,
+    }
+}
+
+#[test]
+fn from_model_maps_renamed_fields() {
+    use renamed_from::{Device, DeviceCreate, DeviceGet};
+
+    let device = Device {
+        id: 3,
+        name: "sensor".to_string(),
+        owner_id: Some(9),
+    };
+
+    let get = DeviceGet::from(device.clone());
+    assert_eq!(get.device_id, 3);
+    assert_eq!(get.label.as_deref(), Some("sensor"));
+    assert_eq!(get.owner, Some(9));
+
+    let create = DeviceCreate::from(device.clone());
+    assert_eq!(create.label.as_deref(), Some("sensor"));
+    assert_eq!(create.owner, Some(9));
+
+    let get = device.to_view::<model_views::ViewModeGet>();
+    assert_eq!(get.device_id, 3);
+}
+
+#[derive(Debug, Views)]
+pub struct Wrapper<T>
+where
+    T: Send + Clone,
+{
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub value: T,
+    #[views(get = "optional", create = "forbidden", patch = "forbidden")]
+    pub history: Vec<T>,
+    #[views(get = "flatten_option", create = "forbidden", patch = "forbidden")]
+    pub previous: Option<T>,
+}
+
+#[test]
+fn where_clauses_are_kept_alongside_view_bounds() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let get = WrapperGet::<u32> {
+        value: 1,
+        history: Some(vec![0]),
+        previous: None,
+    };
+    assert_send(&get);
+    assert_eq!(get.value, 1);
+
+    let create = WrapperCreate::<String> {
+        value: "a".to_string(),
+    };
+    assert_eq!(create.value, "a");
+
+    let patch = WrapperPatch::<u32> {
+        value: Patch::Update(3),
+    };
+    assert_eq!(patch.value, Patch::Update(3));
+}
+
+#[derive(Views)]
+#[views(get_ref, create_derive(Debug))]
+pub struct Login {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(redact, get = "forbidden", create = "required", patch = "patch")]
+    pub password: String,
+    #[views(redact, get = "required", create = "forbidden", patch = "forbidden")]
+    pub api_key: String,
+}
+
+#[test]
+fn redacted_fields_are_hidden_in_debug_output() {
+    let create = LoginCreate {
+        name: "alice".to_string(),
+        password: "hunter2".to_string(),
+    };
+    assert_eq!(
+        format!("{create:?}"),
+        r#"LoginCreate { name: "alice", password: *** }"#
+    );
+
+    let get = LoginGet {
+        name: "alice".to_string(),
+        api_key: "secret".to_string(),
+    };
+    assert_eq!(
+        format!("{get:?}"),
+        r#"LoginGet { name: "alice", api_key: *** }"#
+    );
+    assert_eq!(
+        format!("{:?}", get.as_ref_view()),
+        r#"LoginGetRef { name: "alice", api_key: *** }"#
+    );
+
+    let patch = LoginPatch {
+        name: Patch::Ignore,
+        password: Patch::Update("hunter3".to_string()),
+    };
+    assert!(!format!("{patch:?}").contains("hunter3"));
+}
+
+#[derive(Views)]
+#[views(get_from, get_eq_model)]
+pub struct Reading {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub sensor: String,
+    #[views(get = "optional", create = "required", patch = "patch")]
+    pub celsius: i32,
+    #[views(get = "flatten_option", create = "optional", patch = "optional")]
+    pub note: Option<String>,
+    #[views(get = "forbidden", create = "required", patch = "patch")]
+    pub raw: u64,
+}
+
+#[test]
+fn get_view_matches_its_model() {
+    let reading = Reading {
+        sensor: "kitchen".to_string(),
+        celsius: 21,
+        note: None,
+        raw: 0x15,
+    };
+    let get = ReadingGet::from(Reading {
+        sensor: "kitchen".to_string(),
+        celsius: 21,
+        note: None,
+        raw: 0,
+    });
+    // Forbidden fields are not compared
+    assert!(get.matches_model(&reading));
+
+    // Absent optional fields match any model value
+    let get = ReadingGet {
+        celsius: None,
+        ..get
+    };
+    assert!(get.matches_model(&reading));
+
+    let get = ReadingGet {
+        celsius: Some(22),
+        ..get
+    };
+    assert!(!get.matches_model(&reading));
+
+    let get = ReadingGet {
+        celsius: Some(21),
+        note: Some("calibrated".to_string()),
+        ..get
+    };
+    assert!(!get.matches_model(&reading));
+}
+
+mod internal {
+    use model_views::Views;
+
+    #[derive(Views)]
+    #[views(public, get_from)]
+    pub(crate) struct Session {
+        #[views(get = "required", create = "forbidden", patch = "forbidden")]
+        pub token: String,
+        #[views(get = "required", create = "required", patch = "patch")]
+        pub ttl: u32,
+    }
+}
+
+// Re-exporting the views publicly only compiles if they are `pub`
+pub use internal::{SessionCreate, SessionGet, SessionPatch};
+
+#[test]
+fn public_views_outlive_the_model_visibility() {
+    let get = SessionGet::from(internal::Session {
+        token: "abc".to_string(),
+        ttl: 60,
+    });
+    assert_eq!((get.token.as_str(), get.ttl), ("abc", 60));
+
+    let create = SessionCreate { ttl: 30 };
+    assert_eq!(create.ttl, 30);
+
+    let patch = SessionPatch::default();
+    assert_eq!(patch.ttl, Patch::Ignore);
+}
+
+/// Stands in for another crate's newtype without a `View` impl
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForeignId(u64);
+
+impl From<ForeignId> for u64 {
+    fn from(id: ForeignId) -> Self {
+        id.0
+    }
+}
+
+impl From<u64> for ForeignId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+#[derive(Views)]
+#[views(get_from, create_from, patch_apply)]
+pub struct Shipment {
+    #[views(via = "u64", get = "required", create = "required", patch = "patch")]
+    pub carrier: ForeignId,
+    #[views(via = "u64", get = "optional", create = "optional", patch = "patch")]
+    pub tracking: ForeignId,
+}
+
+#[test]
+fn via_fields_use_the_substitute_type() {
+    use model_views::ApplyPatch;
+
+    let shipment = Shipment {
+        carrier: ForeignId(7),
+        tracking: ForeignId(42),
+    };
+    let get = ShipmentGet::from(shipment);
+    let (carrier, tracking): (u64, Option<u64>) = (get.carrier, get.tracking);
+    assert_eq!((carrier, tracking), (7, Some(42)));
+
+    let create = ShipmentCreate::from(Shipment {
+        carrier: ForeignId(8),
+        tracking: ForeignId(43),
+    });
+    assert_eq!((create.carrier, create.tracking), (8, Some(43)));
+
+    let mut shipment = Shipment {
+        carrier: ForeignId(7),
+        tracking: ForeignId(42),
+    };
+    shipment.apply_patch(ShipmentPatch {
+        carrier: Patch::Update(9),
+        tracking: Patch::Ignore,
+    });
+    assert_eq!(
+        (shipment.carrier, shipment.tracking),
+        (ForeignId(9), ForeignId(42))
+    );
+}
+
+#[derive(Views)]
+#[views(patch_columns)]
+pub struct Customer {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub display_name: String,
+    #[views(get = "required", create = "optional", patch = "optional")]
+    pub email: Option<String>,
+    #[views(get = "required", create = "required", patch = "replace")]
+    pub tier: u8,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub billing_address: Address,
+}
+
+#[test]
+fn set_columns_lists_updated_fields() {
+    let patch = CustomerPatch {
+        email: Patch::Update(None),
+        tier: Some(2),
+        ..CustomerPatch::default()
+    };
+    assert_eq!(
+        patch.set_columns(),
+        [CustomerPatchColumn::Email, CustomerPatchColumn::Tier]
+    );
+
+    // A nested patch maps to its parent column, whatever it updates itself
+    let patch = CustomerPatch {
+        display_name: Patch::Update("Alice".to_string()),
+        billing_address: Patch::Update(AddressPatch::default()),
+        ..CustomerPatch::default()
+    };
+    let columns = patch.set_columns();
+    assert_eq!(
+        columns,
+        [
+            CustomerPatchColumn::DisplayName,
+            CustomerPatchColumn::BillingAddress
+        ]
+    );
+    assert_eq!(columns[1].name(), "billing_address");
+
+    assert!(CustomerPatch::default().set_columns().is_empty());
+}
+
+#[derive(Views)]
+#[views(get_ref)]
+pub struct Ticket {
+    #[views(get_type = "String", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(via = "String", get = "optional", create = "forbidden", patch = "forbidden")]
+    pub seat: u32,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub row: u8,
+}
+
+#[test]
+fn borrowed_views_borrow_overridden_primitives() {
+    let get = TicketGet {
+        id: "T-1".to_string(),
+        seat: Some("12".to_string()),
+        row: 3,
+    };
+    let view = get.as_ref_view();
+    let (id, seat, row): (&String, Option<&String>, u8) = (view.id, view.seat, view.row);
+    assert_eq!((id.as_str(), seat.map(String::as_str), row), ("T-1", Some("12"), 3));
+}
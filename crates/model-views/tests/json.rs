@@ -0,0 +1,85 @@
+use model_views::Views;
+
+#[derive(Debug, Views)]
+#[views(serde, rename_all = "camelCase")]
+pub struct Event {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub event_id: u64,
+    #[views(get = "required")]
+    pub payload: serde_json::Value,
+}
+
+#[test]
+fn get_view_converts_into_json_value() {
+    let get = EventGet {
+        event_id: 1,
+        payload: serde_json::json!({ "kind": "created" }),
+    };
+
+    let value = serde_json::Value::from(get);
+    assert_eq!(
+        value,
+        serde_json::json!({ "eventId": 1, "payload": { "kind": "created" } })
+    );
+
+    let create: EventCreate =
+        serde_json::from_value(serde_json::json!({ "payload": value })).unwrap();
+    assert_eq!(create.payload["payload"]["kind"], "created");
+}
+
+#[derive(Debug, Views)]
+#[views(serde, get_serde_bound = "T: serde::Serialize")]
+pub struct Page<T> {
+    #[views(opaque, get = "required", create = "forbidden", patch = "forbidden")]
+    pub items: Vec<T>,
+}
+
+#[test]
+fn generic_get_views_convert_into_json_value() {
+    let get = PageGet { items: vec![1, 2] };
+    assert_eq!(
+        serde_json::Value::from(get),
+        serde_json::json!({ "items": [1, 2] })
+    );
+}
+
+#[test]
+fn json_patches_from_serializable_values() {
+    use model_views::Patch;
+    use std::collections::BTreeMap;
+
+    let patch = Patch::update_json("Alice");
+    assert_eq!(patch, Patch::Update(serde_json::json!("Alice")));
+    assert!(!patch.is_json_null());
+
+    let tags = BTreeMap::from([("role", vec!["admin"])]);
+    assert_eq!(
+        Patch::update_json(&tags),
+        Patch::Update(serde_json::json!({ "role": ["admin"] }))
+    );
+
+    let cleared = Patch::update_json(&Option::<u32>::None);
+    assert!(cleared.is_json_null());
+    assert!(!Patch::<serde_json::Value>::Ignore.is_json_null());
+}
+
+#[derive(Debug, Views)]
+#[views(serde, runtime_dispatch)]
+pub struct Note {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub text: String,
+}
+
+#[test]
+fn runtime_dispatch_deserializes_json_by_mode() {
+    use model_views::ViewModeKind;
+
+    let Ok(NoteAnyView::Patch(patch)) =
+        NoteAnyView::deserialize_view_json(ViewModeKind::Patch, r#"{"text":"hi"}"#)
+    else {
+        panic!("expected a Patch view");
+    };
+    assert_eq!(patch.text, model_views::Patch::Update("hi".to_string()));
+
+    assert!(NoteAnyView::deserialize_view_json(ViewModeKind::Get, r#"{"text":"hi"} x"#).is_err());
+}
@@ -0,0 +1,70 @@
+//! Generated view types must stay clean under strict downstream lint configurations.
+#![deny(clippy::pedantic, missing_docs)]
+
+use model_views::Views;
+
+/// A documented model with many flags.
+#[derive(Debug, Views)]
+pub struct Settings {
+    /// Identifier of the settings entry
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    /// Display name
+    #[views(get = "optional", create = "optional", patch = "optional")]
+    pub name: String,
+    /// Whether notifications are sent
+    pub notify: bool,
+    /// Whether the profile is public
+    pub public: bool,
+    /// Whether the account is archived
+    pub archived: bool,
+}
+
+#[test]
+fn generated_types_are_lint_clean() {
+    let settings = SettingsGet {
+        id: 1,
+        name: None,
+        notify: true,
+        public: false,
+        archived: false,
+    };
+    assert_eq!(settings.id, 1);
+}
+
+/// A documented model whose views are deserialized by a runtime mode.
+#[cfg(feature = "serde")]
+#[derive(Debug, Views)]
+#[views(serde, runtime_dispatch)]
+pub struct Release {
+    /// Version of the release
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub version: String,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn runtime_dispatch_is_lint_clean() {
+    let view = ReleaseAnyView::Get(ReleaseGet {
+        version: "1.0".to_string(),
+    });
+    assert_eq!(view.mode(), model_views::ViewModeKind::Get);
+}
+
+/// A documented model that names the columns a patch updates.
+#[derive(Debug, Views)]
+#[views(patch_columns)]
+pub struct Profile {
+    /// Display name of the profile
+    #[views(get = "required", patch = "patch")]
+    pub display_name: String,
+}
+
+#[test]
+fn patch_columns_are_lint_clean() {
+    let patch = ProfilePatch {
+        display_name: model_views::Patch::Update("Ada".to_string()),
+    };
+    assert_eq!(patch.set_columns(), [ProfilePatchColumn::DisplayName]);
+    assert_eq!(ProfilePatchColumn::DisplayName.name(), "display_name");
+}
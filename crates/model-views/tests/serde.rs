@@ -0,0 +1,1060 @@
+use model_views::{Patch, Views};
+use std::collections::HashMap;
+
+#[derive(Debug, Views)]
+#[views(serde, transparent)]
+pub struct Email(pub String);
+
+#[test]
+fn transparent_views_serialize_as_inner_value() {
+    let get = EmailGet("alice@example.com".to_string());
+    assert_eq!(
+        serde_json::to_string(&get).unwrap(),
+        r#""alice@example.com""#
+    );
+
+    let create: EmailCreate = serde_json::from_str(r#""bob@example.com""#).unwrap();
+    assert_eq!(create.0, "bob@example.com");
+
+    let patch: EmailPatch = serde_json::from_str(r#""carol@example.com""#).unwrap();
+    assert_eq!(patch.0, Patch::Update("carol@example.com".to_string()));
+
+    let patch: EmailPatch = serde_json::from_str("null").unwrap();
+    assert_eq!(patch.0, Patch::Ignore);
+}
+
+#[derive(Debug, Views)]
+#[views(
+    serde,
+    rename_all = "kebab-case",
+    get_rename_all = "camelCase",
+    create_rename_all = "snake_case"
+)]
+pub struct Profile {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub display_name: String,
+}
+
+#[test]
+fn rename_all_applies_per_view() {
+    let get = ProfileGet {
+        display_name: "Alice".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_string(&get).unwrap(),
+        r#"{"displayName":"Alice"}"#
+    );
+
+    let create: ProfileCreate = serde_json::from_str(r#"{"display_name":"Bob"}"#).unwrap();
+    assert_eq!(create.display_name, "Bob");
+
+    let patch: ProfilePatch = serde_json::from_str(r#"{"display-name":"Carol"}"#).unwrap();
+    assert_eq!(patch.display_name, Patch::Update("Carol".to_string()));
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Author {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub name: String,
+    #[views(get = "required", create = "required", patch = "replace")]
+    pub email: String,
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Article {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub title: String,
+    #[views(get = "required", create = "required", patch = "optional")]
+    pub author: Author,
+    #[views(get = "required", create = "required", patch = "required")]
+    pub editor: Author,
+}
+
+#[test]
+fn patch_serialization_skips_nested_noops() {
+    use model_views::NoopPatch;
+
+    let patch = ArticlePatch {
+        title: Patch::Update("New title".to_string()),
+        author: Patch::Update(Some(AuthorPatch::default())),
+        editor: Patch::Update(AuthorPatch::default()),
+    };
+    assert!(!patch.is_noop());
+    assert_eq!(
+        serde_json::to_string(&patch).unwrap(),
+        r#"{"title":"New title"}"#
+    );
+
+    let patch = ArticlePatch {
+        title: Patch::Ignore,
+        author: Patch::Update(Some(AuthorPatch {
+            name: Patch::Ignore,
+            email: Some("alice@example.com".to_string()),
+        })),
+        editor: Patch::Ignore,
+    };
+    assert_eq!(
+        serde_json::to_string(&patch).unwrap(),
+        r#"{"author":{"email":"alice@example.com"}}"#
+    );
+
+    let patch = ArticlePatch {
+        author: Patch::Update(None),
+        ..ArticlePatch::default()
+    };
+    assert_eq!(serde_json::to_string(&patch).unwrap(), r#"{"author":null}"#);
+    assert!(ArticlePatch::default().is_noop());
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl<M: model_views::ViewMode> model_views::View<M> for Value {
+    type Type = Self;
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Listing {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub title: String,
+    #[views(get = "required", create = "required", patch = "required")]
+    #[serde(flatten)]
+    pub extras: HashMap<String, Value>,
+}
+
+#[test]
+fn flattened_fields_keep_their_serde_attributes() {
+    let get = ListingGet {
+        title: "Bike".to_string(),
+        extras: HashMap::from([("price".to_string(), Value::Number(120))]),
+    };
+    assert_eq!(
+        serde_json::to_string(&get).unwrap(),
+        r#"{"title":"Bike","price":120}"#
+    );
+
+    let create: ListingCreate =
+        serde_json::from_str(r#"{"title":"Bike","color":"red","price":120}"#).unwrap();
+    assert_eq!(create.title, "Bike");
+    assert_eq!(
+        create.extras,
+        HashMap::from([
+            ("color".to_string(), Value::Text("red".to_string())),
+            ("price".to_string(), Value::Number(120)),
+        ])
+    );
+
+    let patch: ListingPatch = serde_json::from_str(r#"{"color":"blue"}"#).unwrap();
+    assert_eq!(patch.title, Patch::Ignore);
+    assert_eq!(
+        patch.extras,
+        Patch::Update(HashMap::from([(
+            "color".to_string(),
+            Value::Text("blue".to_string())
+        )]))
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde, get_ref, rename_all = "camelCase")]
+pub struct Comment {
+    #[views(get = "required")]
+    pub comment_id: u64,
+    #[views(get = "required")]
+    pub body: String,
+    #[views(get = "optional")]
+    pub author: Author,
+}
+
+#[test]
+fn borrowed_get_view_serializes_like_owned_view() {
+    let get = CommentGet {
+        comment_id: 7,
+        body: "Nice".to_string(),
+        author: Some(AuthorGet {
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        }),
+    };
+
+    let borrowed: CommentGetRef<'_> = (&get).into();
+    assert_eq!(borrowed.comment_id, 7);
+    assert!(std::ptr::eq(borrowed.body, &get.body));
+    assert_eq!(
+        serde_json::to_string(&borrowed).unwrap(),
+        serde_json::to_string(&get).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_string(&get.as_ref_view()).unwrap(),
+        r#"{"commentId":7,"body":"Nice","author":{"name":"Alice","email":"alice@example.com"}}"#
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Team {
+    #[views(create = "required")]
+    pub name: String,
+    #[views(create = "required", create_serde_default)]
+    pub members: Vec<String>,
+}
+
+#[test]
+fn create_serde_default_fills_omitted_fields() {
+    let create: TeamCreate = serde_json::from_str(r#"{"name":"Core"}"#).unwrap();
+    assert_eq!(create.name, "Core");
+    assert!(create.members.is_empty());
+
+    let create: TeamCreate =
+        serde_json::from_str(r#"{"name":"Core","members":["alice"]}"#).unwrap();
+    assert_eq!(create.members, ["alice"]);
+
+    assert!(serde_json::from_str::<TeamCreate>(r#"{"members":[]}"#).is_err());
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Customer {
+    #[views(get = "required", create = "optional", patch = "tri")]
+    pub nickname: String,
+}
+
+#[test]
+fn tri_patch_distinguishes_absent_null_and_value() {
+    use model_views::PatchOpt;
+
+    let patch: CustomerPatch = serde_json::from_str("{}").unwrap();
+    assert_eq!(patch.nickname, PatchOpt::Ignore);
+
+    let patch: CustomerPatch = serde_json::from_str(r#"{"nickname":null}"#).unwrap();
+    assert_eq!(patch.nickname, PatchOpt::SetNull);
+
+    let patch: CustomerPatch = serde_json::from_str(r#"{"nickname":"Al"}"#).unwrap();
+    assert_eq!(patch.nickname, PatchOpt::SetValue("Al".to_string()));
+
+    let json = |nickname| serde_json::to_string(&CustomerPatch { nickname }).unwrap();
+    assert_eq!(json(PatchOpt::Ignore), "{}");
+    assert_eq!(json(PatchOpt::SetNull), r#"{"nickname":null}"#);
+    assert_eq!(
+        json(PatchOpt::SetValue("Al".to_string())),
+        r#"{"nickname":"Al"}"#
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde, rename_all = "camelCase")]
+pub struct Ticket {
+    #[views(create = "forbidden", create_accept_forbidden)]
+    pub ticket_id: u64,
+    #[views(create = "required")]
+    pub summary: String,
+}
+
+#[test]
+fn accepted_forbidden_create_fields_are_discarded() {
+    let create: TicketCreate =
+        serde_json::from_str(r#"{"ticketId":42,"summary":"Broken"}"#).unwrap();
+    assert_eq!(create.summary, "Broken");
+
+    let create: TicketCreate = serde_json::from_str(r#"{"summary":"Broken"}"#).unwrap();
+    assert_eq!(create.summary, "Broken");
+
+    assert!(serde_json::from_str::<TicketCreate>(r#"{"summary":"Broken","other":1}"#).is_err());
+
+    let _create = TicketCreate {
+        ticket_id: serde::de::IgnoredAny,
+        summary: "Broken".to_string(),
+    };
+}
+
+#[derive(Debug, Views)]
+#[views(serde, patch_null_is_error)]
+pub struct Repository {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub name: String,
+    #[views(get = "required", create = "optional", patch = "optional")]
+    pub description: String,
+}
+
+#[test]
+fn patch_null_is_error_rejects_explicit_null() {
+    let patch: RepositoryPatch = serde_json::from_str("{}").unwrap();
+    assert_eq!(patch.name, Patch::Ignore);
+
+    let patch: RepositoryPatch = serde_json::from_str(r#"{"name":"core"}"#).unwrap();
+    assert_eq!(patch.name, Patch::Update("core".to_string()));
+
+    let Err(err) = serde_json::from_str::<RepositoryPatch>(r#"{"name":null}"#) else {
+        panic!("expected explicit null to be rejected");
+    };
+    assert!(err.to_string().contains("explicit null is not allowed"));
+
+    let patch: RepositoryPatch = serde_json::from_str(r#"{"description":null}"#).unwrap();
+    assert_eq!(patch.description, Patch::Update(None));
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Label {
+    #[views(
+        get = "required",
+        create = "required",
+        patch = "patch",
+        patch_skip_if = "skip_blank"
+    )]
+    pub text: String,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub color: String,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn skip_blank(patch: &Patch<String>) -> bool {
+    patch
+        .as_option_ref()
+        .is_none_or(|text| text.trim().is_empty())
+}
+
+#[test]
+fn patch_skip_if_overrides_default_skip() {
+    let patch = LabelPatch {
+        text: Patch::Update("  ".to_string()),
+        color: Patch::Update("red".to_string()),
+    };
+    assert_eq!(serde_json::to_string(&patch).unwrap(), r#"{"color":"red"}"#);
+
+    let patch = LabelPatch {
+        text: Patch::Update("bug".to_string()),
+        color: Patch::Ignore,
+    };
+    assert_eq!(serde_json::to_string(&patch).unwrap(), r#"{"text":"bug"}"#);
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Folder {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(get = "required", create = "optional", patch = "patch")]
+    pub parent: Option<Box<Folder>>,
+}
+
+#[test]
+fn self_referential_views_serialize() {
+    let get = FolderGet {
+        name: "docs".to_string(),
+        parent: Some(Box::new(FolderGet {
+            name: "home".to_string(),
+            parent: None,
+        })),
+    };
+    assert_eq!(
+        serde_json::to_string(&get).unwrap(),
+        r#"{"name":"docs","parent":{"name":"home","parent":null}}"#
+    );
+
+    let patch: FolderPatch = serde_json::from_str(r#"{"parent":{"name":"root"}}"#).unwrap();
+    let Patch::Update(Some(parent)) = patch.parent else {
+        panic!("expected a parent update");
+    };
+    assert_eq!(parent.name, Patch::Update("root".to_string()));
+}
+
+fn trimmed<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: String = serde::Deserialize::deserialize(deserializer)?;
+    Ok(value.trim().to_string())
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Handle {
+    #[views(
+        get = "required",
+        create = "required",
+        patch = "patch",
+        create_deserialize_with = "trimmed"
+    )]
+    pub name: String,
+}
+
+#[test]
+fn create_deserialize_with_applies_to_create_only() {
+    let create: HandleCreate = serde_json::from_str(r#"{"name":"  alice "}"#).unwrap();
+    assert_eq!(create.name, "alice");
+
+    let patch: HandlePatch = serde_json::from_str(r#"{"name":"  alice "}"#).unwrap();
+    assert_eq!(patch.name, Patch::Update("  alice ".to_string()));
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Fill {
+    Solid { color: String },
+    Gradient { from: String, to: String },
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum FillPatch {
+    Solid {
+        #[serde(default, skip_serializing_if = "Patch::is_ignore")]
+        color: Patch<String>,
+    },
+    Gradient {
+        #[serde(default, skip_serializing_if = "Patch::is_ignore")]
+        from: Patch<String>,
+        #[serde(default, skip_serializing_if = "Patch::is_ignore")]
+        to: Patch<String>,
+    },
+}
+
+impl model_views::View<model_views::ViewModeGet> for Fill {
+    type Type = Self;
+}
+
+impl model_views::View<model_views::ViewModeCreate> for Fill {
+    type Type = Self;
+}
+
+impl model_views::View<model_views::ViewModePatch> for Fill {
+    type Type = FillPatch;
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Panel {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub fill: Fill,
+}
+
+#[test]
+fn enum_field_patches_round_trip_adjacently_tagged() {
+    let patch = PanelPatch {
+        fill: Patch::Update(FillPatch::Gradient {
+            from: Patch::Ignore,
+            to: Patch::Update("blue".to_string()),
+        }),
+    };
+    let json = serde_json::to_string(&patch).unwrap();
+    assert_eq!(json, r#"{"fill":{"kind":"Gradient","data":{"to":"blue"}}}"#);
+
+    let back: PanelPatch = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.fill, patch.fill);
+
+    let back: PanelPatch = serde_json::from_str("{}").unwrap();
+    assert_eq!(back.fill, Patch::Ignore);
+
+    let create: PanelCreate =
+        serde_json::from_str(r#"{"fill":{"Solid":{"color":"red"}}}"#).unwrap();
+    assert_eq!(
+        create.fill,
+        Fill::Solid {
+            color: "red".to_string()
+        }
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde, get_ref, skip_serializing_none)]
+pub struct Contact {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub phone: Option<String>,
+    #[views(get = "optional", create = "optional", patch = "optional")]
+    pub email: String,
+    #[views(get = "flatten_option", create = "required", patch = "tri")]
+    pub note: Option<String>,
+}
+
+#[test]
+fn skip_serializing_none_omits_null_options() {
+    let get = ContactGet {
+        name: "Alice".to_string(),
+        phone: None,
+        email: None,
+        note: None,
+    };
+    assert_eq!(serde_json::to_string(&get).unwrap(), r#"{"name":"Alice"}"#);
+
+    let name = "Bob".to_string();
+    let phone = Some("123".to_string());
+    let get_ref = ContactGetRef {
+        name: &name,
+        phone: &phone,
+        email: None,
+        note: None,
+    };
+    assert_eq!(
+        serde_json::to_string(&get_ref).unwrap(),
+        r#"{"name":"Bob","phone":"123"}"#
+    );
+
+    let create: ContactCreate = serde_json::from_str(r#"{"name":"Bob"}"#).unwrap();
+    assert_eq!(create.phone, None);
+    assert_eq!(create.note, None);
+}
+
+#[test]
+fn skip_serializing_none_borrowed_views_match_owned_ones() {
+    let get = ContactGet {
+        name: "Alice".to_string(),
+        phone: None,
+        email: None,
+        note: None,
+    };
+    assert_eq!(
+        serde_json::to_value(get.as_ref_view()).unwrap(),
+        serde_json::to_value(&get).unwrap()
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Settings {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub theme: String,
+    #[views(get = "optional", create = "optional", patch = "optional")]
+    pub locale: String,
+    #[views(get = "required", create = "required", patch = "replace")]
+    pub volume: u8,
+    #[views(get = "flatten_option", create = "required", patch = "tri")]
+    pub motto: Option<String>,
+}
+
+#[test]
+fn omitted_patch_fields_are_ignored() {
+    let patch: SettingsPatch = serde_json::from_str(r#"{"locale":"de"}"#).unwrap();
+    assert_eq!(patch.theme, Patch::Ignore);
+    assert_eq!(patch.locale, Patch::Update(Some("de".to_string())));
+    assert_eq!(patch.volume, None);
+    assert!(patch.motto.is_ignore());
+}
+
+/// Implements `Serialize` and `Deserialize` without `T` doing so, which the bounds serde
+/// infers for the generic views would require.
+pub struct Opaque<T>(pub std::marker::PhantomData<T>);
+
+impl<T> model_views::View<model_views::ViewModeGet> for Opaque<T> {
+    type Type = Self;
+}
+
+impl<T> model_views::View<model_views::ViewModeCreate> for Opaque<T> {
+    type Type = Self;
+}
+
+impl<T> model_views::View<model_views::ViewModePatch> for Opaque<T> {
+    type Type = Self;
+}
+
+impl<T> serde::Serialize for Opaque<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Opaque<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <()>::deserialize(deserializer)?;
+        Ok(Self(std::marker::PhantomData))
+    }
+}
+
+pub struct NotSerde;
+
+#[derive(Views)]
+#[views(serde, serde_bound = "")]
+pub struct Tagged<T> {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub marker: Opaque<T>,
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub count: u32,
+}
+
+#[test]
+fn serde_bound_replaces_inferred_bounds() {
+    let get = TaggedGet::<NotSerde> {
+        marker: Opaque(std::marker::PhantomData),
+        count: 3,
+    };
+    assert_eq!(
+        serde_json::to_string(&get).unwrap(),
+        r#"{"marker":null,"count":3}"#
+    );
+
+    let create: TaggedCreate<NotSerde> = serde_json::from_str(r#"{"marker":null}"#).unwrap();
+    let _ = create.marker;
+
+    let patch: TaggedPatch<NotSerde> = serde_json::from_str("{}").unwrap();
+    assert!(patch.marker.is_ignore());
+}
+
+#[test]
+fn patch_views_accept_subsets_but_reject_unknown_fields() {
+    let patch: SettingsPatch = serde_json::from_str("{}").unwrap();
+    assert_eq!(patch.theme, Patch::Ignore);
+    assert_eq!(patch.locale, Patch::Ignore);
+    assert_eq!(patch.volume, None);
+    assert!(patch.motto.is_ignore());
+
+    let patch: SettingsPatch = serde_json::from_str(r#"{"theme":"dark","volume":3}"#).unwrap();
+    assert_eq!(patch.theme, Patch::Update("dark".to_string()));
+    assert_eq!(patch.volume, Some(3));
+
+    let Err(err) = serde_json::from_str::<SettingsPatch>(r#"{"theme":"dark","colour":"red"}"#)
+    else {
+        panic!("unknown fields must be rejected");
+    };
+    assert!(err.to_string().contains("unknown field `colour`"));
+}
+
+const fn no_limit() -> i32 {
+    -1
+}
+
+#[derive(Debug, Views)]
+#[views(serde, create_new, patch_to_create)]
+pub struct Quota {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(
+        get = "required",
+        create = "optional",
+        patch = "patch",
+        create_sentinel = "no_limit"
+    )]
+    pub limit: i32,
+}
+
+#[test]
+fn create_sentinel_replaces_option_for_absent_values() {
+    let create: QuotaCreate = serde_json::from_str(r#"{"name":"disk"}"#).unwrap();
+    assert_eq!(create.limit, -1);
+
+    let create: QuotaCreate = serde_json::from_str(r#"{"name":"disk","limit":10}"#).unwrap();
+    assert_eq!(create.limit, 10);
+
+    assert_eq!(QuotaCreate::new("cpu".to_string()).limit, -1);
+
+    let patch = QuotaPatch {
+        name: Patch::Update("mem".to_string()),
+        limit: Patch::Ignore,
+    };
+    let Ok(create) = QuotaCreate::try_from(patch) else {
+        panic!("all required fields are present");
+    };
+    assert_eq!(create.limit, -1);
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Member {
+    #[views(
+        get = "required",
+        create = "required",
+        patch = "patch",
+        alias = "userName",
+        alias = "user_name"
+    )]
+    pub name: String,
+}
+
+#[test]
+fn aliases_are_accepted_when_deserializing() {
+    let create: MemberCreate = serde_json::from_str(r#"{"userName":"alice"}"#).unwrap();
+    assert_eq!(create.name, "alice");
+
+    let patch: MemberPatch = serde_json::from_str(r#"{"user_name":"bob"}"#).unwrap();
+    assert_eq!(patch.name, Patch::Update("bob".to_string()));
+
+    let get = MemberGet {
+        name: "carol".to_string(),
+    };
+    assert_eq!(serde_json::to_string(&get).unwrap(), r#"{"name":"carol"}"#);
+}
+
+#[derive(Debug, Views)]
+#[views(serde, runtime_dispatch)]
+pub struct Widget {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub title: String,
+}
+
+#[test]
+fn runtime_dispatch_deserializes_view_by_mode() {
+    use model_views::ViewModeKind;
+
+    let dispatch = |mode, json: &str| {
+        WidgetAnyView::deserialize_view(mode, &mut serde_json::Deserializer::from_str(json))
+    };
+
+    let Ok(WidgetAnyView::Get(get)) = dispatch(ViewModeKind::Get, r#"{"id":1,"title":"a"}"#) else {
+        panic!("expected a Get view");
+    };
+    assert_eq!((get.id, get.title.as_str()), (1, "a"));
+
+    let Ok(view) = dispatch(ViewModeKind::Create, r#"{"title":"b"}"#) else {
+        panic!("expected a Create view");
+    };
+    assert_eq!(view.mode(), ViewModeKind::Create);
+    assert!(matches!(view, WidgetAnyView::Create(create) if create.title == "b"));
+
+    let Ok(WidgetAnyView::Patch(patch)) = dispatch(ViewModeKind::Patch, "{}") else {
+        panic!("expected a Patch view");
+    };
+    assert_eq!(patch.title, Patch::Ignore);
+
+    // The Create view does not accept the id
+    assert!(dispatch(ViewModeKind::Create, r#"{"id":1,"title":"b"}"#).is_err());
+}
+
+#[derive(Debug, Views)]
+#[views(
+    serde,
+    get_from,
+    get_ref,
+    get_accessors,
+    create_new,
+    create_introspect,
+    patch_apply,
+    patch_to_create
+)]
+pub struct Token {
+    #[views(get = "required", create = "required", patch = "required")]
+    pub r#type: String,
+    #[views(get = "required", create = "optional", patch = "required")]
+    pub r#match: String,
+}
+
+#[test]
+fn raw_identifier_fields_use_unprefixed_names() {
+    let token = Token {
+        r#type: "bearer".to_string(),
+        r#match: "*".to_string(),
+    };
+    let get = TokenGet::from(token);
+    assert_eq!(get.r#type(), "bearer");
+    assert_eq!(
+        serde_json::to_string(&get).unwrap(),
+        r#"{"type":"bearer","match":"*"}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&get.as_ref_view()).unwrap(),
+        r#"{"type":"bearer","match":"*"}"#
+    );
+
+    let create: TokenCreate = serde_json::from_str(r#"{"type":"basic","match":"*"}"#).unwrap();
+    assert_eq!(create.r#type, "basic");
+    assert_eq!(create.r#match.as_deref(), Some("*"));
+    assert_eq!(
+        TokenCreate::new("basic".to_string()).missing_optionals(),
+        ["match"]
+    );
+
+    let patch: TokenPatch = serde_json::from_str(r#"{"type":"digest","match":"?"}"#).unwrap();
+    assert_eq!(patch.r#type, Patch::Update("digest".to_string()));
+    assert_eq!(patch.r#match, Patch::Update("?".to_string()));
+    assert_eq!(
+        serde_json::to_string(&patch).unwrap(),
+        r#"{"type":"digest","match":"?"}"#
+    );
+
+    let mut token = Token {
+        r#type: "bearer".to_string(),
+        r#match: "*".to_string(),
+    };
+    model_views::ApplyPatch::apply_patch(&mut token, patch);
+    assert_eq!(
+        (token.r#type.as_str(), token.r#match.as_str()),
+        ("digest", "?")
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Coordinates {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub lat: f64,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub lon: f64,
+    #[views(get = "optional", create = "optional", patch = "forbidden")]
+    pub altitude: f64,
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Landmark {
+    #[views(get = "required", create = "forbidden", patch = "forbidden")]
+    pub id: u64,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(get = "required", create = "required", patch = "patch")]
+    #[serde(flatten)]
+    pub position: Coordinates,
+}
+
+#[test]
+fn flattened_create_fields_are_inlined() {
+    let create: LandmarkCreate =
+        serde_json::from_str(r#"{"name":"Summit","lat":47.1,"lon":11.4}"#).unwrap();
+    assert_eq!(create.name, "Summit");
+    assert_eq!((create.position.lat, create.position.lon), (47.1, 11.4));
+    assert_eq!(create.position.altitude, None);
+
+    let create: LandmarkCreate =
+        serde_json::from_str(r#"{"name":"Summit","lat":47.1,"lon":11.4,"altitude":3798.0}"#)
+            .unwrap();
+    assert_eq!(create.position.altitude, Some(3798.0));
+
+    // The flattened view's required fields stay required
+    assert!(serde_json::from_str::<LandmarkCreate>(r#"{"name":"Summit","lat":47.1}"#).is_err());
+
+    let get = LandmarkGet {
+        id: 1,
+        name: "Summit".to_string(),
+        position: CoordinatesGet {
+            lat: 47.1,
+            lon: 11.4,
+            altitude: None,
+        },
+    };
+    assert_eq!(
+        serde_json::to_string(&get).unwrap(),
+        r#"{"id":1,"name":"Summit","lat":47.1,"lon":11.4,"altitude":null}"#
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde, create_xor = ["email", "phone"])]
+pub struct Recipient {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(get = "optional", create = "optional", patch = "optional")]
+    pub email: String,
+    #[views(get = "optional", create = "optional", patch = "optional")]
+    pub phone: String,
+}
+
+#[test]
+fn create_xor_requires_exactly_one_field() {
+    let create: RecipientCreate =
+        serde_json::from_str(r#"{"name":"Alice","email":"alice@example.com"}"#).unwrap();
+    assert_eq!(create.email.as_deref(), Some("alice@example.com"));
+    assert_eq!(create.phone, None);
+
+    let create: RecipientCreate = serde_json::from_str(r#"{"name":"Bob","phone":"555"}"#).unwrap();
+    assert_eq!(create.phone.as_deref(), Some("555"));
+
+    let Err(err) = serde_json::from_str::<RecipientCreate>(
+        r#"{"name":"Carol","email":"carol@example.com","phone":"555"}"#,
+    ) else {
+        panic!("both fields are present");
+    };
+    assert!(
+        err.to_string()
+            .contains("expected exactly one of email, phone, found email, phone")
+    );
+
+    let Err(err) = serde_json::from_str::<RecipientCreate>(r#"{"name":"Dave"}"#) else {
+        panic!("no field is present");
+    };
+    assert!(
+        err.to_string()
+            .contains("expected exactly one of email, phone, found none")
+    );
+
+    // The Patch view is not constrained
+    let patch: RecipientPatch = serde_json::from_str(r#"{"email":"a@b.c","phone":"1"}"#).unwrap();
+    assert!(patch.email.is_update_and(Option::is_some));
+}
+
+#[derive(Debug, Views)]
+#[views(serde, runtime_dispatch, create_allow_unknown_fields)]
+pub struct Device {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub label: String,
+}
+
+#[derive(Debug, Views)]
+#[views(serde, patch_allow_unknown_fields)]
+pub struct Gauge {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub reading: u32,
+}
+
+#[test]
+fn allow_unknown_fields_relaxes_only_its_view() {
+    use model_views::ViewModeKind;
+
+    let json = r#"{"label":"sensor","firmware":"2.0"}"#;
+    let dispatch =
+        |mode| DeviceAnyView::deserialize_view(mode, &mut serde_json::Deserializer::from_str(json));
+
+    let Ok(DeviceAnyView::Create(create)) = dispatch(ViewModeKind::Create) else {
+        panic!("expected a Create view");
+    };
+    assert_eq!(create.label, "sensor");
+
+    assert!(dispatch(ViewModeKind::Get).is_err());
+    assert!(dispatch(ViewModeKind::Patch).is_err());
+
+    let json = r#"{"reading":3,"unit":"bar"}"#;
+    let patch: GaugePatch = serde_json::from_str(json).unwrap();
+    assert_eq!(patch.reading, Patch::Update(3));
+    assert!(serde_json::from_str::<GaugeCreate>(json).is_err());
+}
+
+#[derive(Debug, Views)]
+#[views(serde, merge_patch_serialize)]
+pub struct Place {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub city: String,
+}
+
+#[derive(Debug, Views)]
+#[views(serde, merge_patch_serialize, rename_all = "camelCase")]
+pub struct Venue {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub display_name: String,
+    #[views(get = "required", create = "optional", patch = "optional")]
+    pub phone_number: String,
+    #[views(get = "required", create = "required", patch = "replace")]
+    #[serde(rename = "cap")]
+    pub capacity: u32,
+    #[views(get = "required", create = "optional", patch = "tri")]
+    pub website: String,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub place: Place,
+}
+
+#[test]
+fn merge_patch_serialize_follows_rfc_7396() {
+    use model_views::PatchOpt;
+
+    // Ignored fields are omitted
+    assert_eq!(serde_json::to_string(&VenuePatch::default()).unwrap(), "{}");
+
+    // Updates set values, and `Update(None)` deletes with `null`
+    let patch = VenuePatch {
+        display_name: Patch::Update("Hall".to_string()),
+        phone_number: Patch::Update(None),
+        capacity: Some(300),
+        ..VenuePatch::default()
+    };
+    assert_eq!(
+        serde_json::to_string(&patch).unwrap(),
+        r#"{"displayName":"Hall","phoneNumber":null,"cap":300}"#
+    );
+
+    let patch = VenuePatch {
+        phone_number: Patch::Update(Some("555".to_string())),
+        website: PatchOpt::SetNull,
+        place: Patch::Update(PlacePatch {
+            city: Patch::Update("Graz".to_string()),
+        }),
+        ..VenuePatch::default()
+    };
+    assert_eq!(
+        serde_json::to_string(&patch).unwrap(),
+        r#"{"phoneNumber":"555","website":null,"place":{"city":"Graz"}}"#
+    );
+
+    // The document deserializes back into the same patch
+    let patch: VenuePatch =
+        serde_json::from_str(r#"{"phoneNumber":null,"website":"https://example.com"}"#).unwrap();
+    assert_eq!(patch.phone_number, Patch::Update(None));
+    assert_eq!(
+        patch.website,
+        PatchOpt::SetValue("https://example.com".to_string())
+    );
+    assert_eq!(
+        serde_json::to_string(&patch).unwrap(),
+        r#"{"phoneNumber":null,"website":"https://example.com"}"#
+    );
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Memo {
+    #[views(get = "optional", create = "required", patch = "patch")]
+    #[serde(rename = "text", skip_serializing_if = "String::is_empty")]
+    pub body: String,
+}
+
+#[test]
+fn type_dependent_serde_attributes_are_not_forwarded() {
+    // `rename` still applies, while `skip_serializing_if` was written for the model's `String`
+    let get = MemoGet {
+        body: Some(String::new()),
+    };
+    assert_eq!(serde_json::to_string(&get).unwrap(), r#"{"text":""}"#);
+
+    let create: MemoCreate = serde_json::from_str(r#"{"text":"hello"}"#).unwrap();
+    assert_eq!(create.body, "hello");
+
+    let patch = MemoPatch {
+        body: Patch::Update(String::new()),
+    };
+    assert_eq!(serde_json::to_string(&patch).unwrap(), r#"{"text":""}"#);
+}
+
+/// Has no `Deserialize` impl, only a custom deserializer
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Fingerprint(String);
+
+fn fingerprint<'de, D>(deserializer: D) -> Result<Fingerprint, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: String = serde::Deserialize::deserialize(deserializer)?;
+    Ok(Fingerprint(value.to_uppercase()))
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Enrollment {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(
+        opaque,
+        get = "forbidden",
+        create = "required",
+        patch = "forbidden",
+        create_deserialize_with = "fingerprint"
+    )]
+    pub fingerprint: Fingerprint,
+}
+
+#[test]
+fn create_deserialize_with_needs_no_deserialize_impl() {
+    let create: EnrollmentCreate =
+        serde_json::from_str(r#"{"name":"laptop","fingerprint":"ab:cd"}"#).unwrap();
+    assert_eq!(create.fingerprint, Fingerprint("AB:CD".to_string()));
+}
+
+#[derive(Debug, Views)]
+#[views(serde)]
+pub struct Pairing {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub name: String,
+    #[views(opaque, get = "forbidden", create = "required", patch = "forbidden")]
+    #[serde(skip_deserializing)]
+    pub fingerprint: Fingerprint,
+}
+
+#[test]
+fn skipped_create_fields_need_no_deserialize_impl() {
+    let create: PairingCreate = serde_json::from_str(r#"{"name":"laptop"}"#).unwrap();
+    assert_eq!(create.name, "laptop");
+    assert_eq!(create.fingerprint, Fingerprint::default());
+}
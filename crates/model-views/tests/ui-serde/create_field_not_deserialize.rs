@@ -0,0 +1,16 @@
+use model_views::{View, ViewMode, Views};
+
+pub struct Secret;
+
+impl<M: ViewMode> View<M> for Secret {
+    type Type = Secret;
+}
+
+#[derive(Views)]
+#[views(serde)]
+pub struct Vault {
+    #[views(get = "forbidden", create = "required", patch = "forbidden")]
+    pub secret: Secret,
+}
+
+fn main() {}
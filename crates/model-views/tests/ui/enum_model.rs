@@ -0,0 +1,9 @@
+use model_views::Views;
+
+#[derive(Views)]
+pub enum Status {
+    Active,
+    Inactive,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use model_views::Views;
+
+#[derive(Views)]
+#[views(get_try_into)]
+pub struct User {
+    #[views(get = "forbidden")]
+    pub password: String,
+}
+
+fn main() {}
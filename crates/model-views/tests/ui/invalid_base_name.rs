@@ -0,0 +1,9 @@
+use model_views::Views;
+
+#[derive(Views)]
+#[views(base_name = "User-Model")]
+pub struct User {
+    pub name: String,
+}
+
+fn main() {}
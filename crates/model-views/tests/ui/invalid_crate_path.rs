@@ -0,0 +1,9 @@
+use model_views::Views;
+
+#[derive(Views)]
+#[views(crate = "not a path")]
+pub struct User {
+    pub name: String,
+}
+
+fn main() {}
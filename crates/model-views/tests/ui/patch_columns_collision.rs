@@ -0,0 +1,12 @@
+use model_views::Views;
+
+#[derive(Views)]
+#[views(patch_columns)]
+pub struct User {
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub foo: String,
+    #[views(get = "required", create = "required", patch = "patch")]
+    pub _foo: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use model_views::Views;
+
+#[derive(Views)]
+pub struct Point(pub i32, pub i32);
+
+fn main() {}
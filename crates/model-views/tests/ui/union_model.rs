@@ -0,0 +1,9 @@
+use model_views::Views;
+
+#[derive(Views)]
+pub union Bits {
+    pub int: u32,
+    pub float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use model_views::Views;
+
+#[derive(Views)]
+pub struct User {
+    #[views(get = "sometimes")]
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use model_views::Views;
+
+#[derive(Views)]
+#[views(create_validate = "validate_user")]
+pub struct User {
+    pub name: String,
+}
+
+fn main() {}